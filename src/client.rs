@@ -55,10 +55,9 @@ impl Client {
         refresh: bool,
     ) -> Result<(), LinkedinError> {
         let url = Url::parse("https://www.linkedin.com")?;
-        if !refresh
-            && self.load_cookies().is_ok() {
-                return Ok(());
-            }
+        if !refresh && self.load_cookies().is_ok() {
+            return Ok(());
+        }
 
         // Request session cookies
         self.request_session_cookies().await?;
@@ -193,4 +192,57 @@ impl Client {
             .await?;
         Ok(res)
     }
+
+    pub async fn delete(&self, uri: &str) -> Result<Response, LinkedinError> {
+        evade().await;
+        let url = format!("{API_BASE_URL}{uri}");
+
+        let mut headers = header::HeaderMap::new();
+        headers.insert("csrf-token", self.get_jsession_id().parse()?);
+
+        let res = self.client.delete(&url).headers(headers).send().await?;
+        Ok(res)
+    }
+
+    /// Headers authenticating a request built outside of this client, such
+    /// as the realtime event stream.
+    pub(crate) fn auth_headers(&self) -> Result<header::HeaderMap, LinkedinError> {
+        let mut headers = header::HeaderMap::new();
+        headers.insert("csrf-token", self.get_jsession_id().parse()?);
+        Ok(headers)
+    }
+
+    /// Upload raw bytes to an absolute URL handed out by a media-upload handshake.
+    /// The session cookies and csrf token are only sent to LinkedIn hosts.
+    pub async fn upload(
+        &self,
+        url: &str,
+        upload_headers: &[(String, String)],
+        bytes: Vec<u8>,
+    ) -> Result<Response, LinkedinError> {
+        evade().await;
+
+        let url = Url::parse(url)?;
+        let (client, mut headers) = if is_linkedin_host(&url) {
+            (self.client.clone(), self.auth_headers()?)
+        } else {
+            (ReqwestClient::new(), header::HeaderMap::new())
+        };
+        for (name, value) in upload_headers {
+            headers.insert(
+                header::HeaderName::from_bytes(name.as_bytes())
+                    .map_err(|e| LinkedinError::InvalidInput(e.to_string()))?,
+                value.parse()?,
+            );
+        }
+
+        let res = client.put(url).headers(headers).body(bytes).send().await?;
+        Ok(res)
+    }
+}
+
+/// Whether `url` points at LinkedIn, and may be sent the session credentials.
+pub fn is_linkedin_host(url: &Url) -> bool {
+    url.host_str()
+        .is_some_and(|host| host == "linkedin.com" || host.ends_with(".linkedin.com"))
 }
@@ -0,0 +1,318 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::linkedin::LinkedinInner;
+use crate::types::{Connection, MiniProfile, UniformResourceName};
+use crate::utils::write_atomically;
+use crate::LinkedinError;
+
+const DEFAULT_MAX_DEPTH: usize = 1;
+const DEFAULT_FAN_OUT: usize = 49;
+const DEFAULT_REQUEST_BUDGET: usize = 100;
+/// Most results LinkedIn returns per search request
+const SEARCH_PAGE_SIZE: usize = 49;
+
+/// A profile reached by a crawl
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrawlNode {
+    pub urn_id: String,
+    pub public_id: Option<String>,
+    pub name: Option<String>,
+    pub headline: Option<String>,
+    /// Network distance from the current user, as reported by search
+    pub distance: Option<String>,
+    /// Hops from the nearest seed
+    pub depth: usize,
+}
+
+/// An undirected connection between two profiles, stored with the smaller
+/// URN id first so each pair appears once.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct CrawlEdge {
+    pub source: String,
+    pub target: String,
+}
+
+impl CrawlEdge {
+    pub fn new(a: &str, b: &str) -> Self {
+        let (source, target) = if a <= b { (a, b) } else { (b, a) };
+        Self {
+            source: source.to_string(),
+            target: target.to_string(),
+        }
+    }
+}
+
+/// Deduplicated nodes and edges, keyed by URN id
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CrawlGraph {
+    pub nodes: BTreeMap<String, CrawlNode>,
+    pub edges: BTreeSet<CrawlEdge>,
+}
+
+impl CrawlGraph {
+    /// Add a node, keeping the shallowest depth and any details already known.
+    pub fn add_node(&mut self, node: CrawlNode) {
+        match self.nodes.get_mut(&node.urn_id) {
+            Some(existing) => {
+                existing.depth = existing.depth.min(node.depth);
+                existing.public_id = existing.public_id.take().or(node.public_id);
+                existing.name = existing.name.take().or(node.name);
+                existing.headline = existing.headline.take().or(node.headline);
+                existing.distance = existing.distance.take().or(node.distance);
+            }
+            None => {
+                self.nodes.insert(node.urn_id.clone(), node);
+            }
+        }
+    }
+
+    pub fn add_edge(&mut self, a: &str, b: &str) {
+        if a != b {
+            self.edges.insert(CrawlEdge::new(a, b));
+        }
+    }
+
+    /// Add connections collected for a profile, linking each to it.
+    pub fn add_connections(&mut self, urn_id: &str, connections: &[Connection], depth: usize) {
+        for connection in connections.iter().filter(|c| !c.urn_id.is_empty()) {
+            self.add_node(CrawlNode::from_connection(connection.clone(), depth));
+            self.add_edge(urn_id, &connection.urn_id);
+        }
+    }
+
+    /// Add mini profiles, such as conversation participants, linking each to
+    /// `urn_id`. Profiles without a URN are skipped.
+    pub fn add_mini_profiles(&mut self, urn_id: &str, profiles: &[MiniProfile], depth: usize) {
+        for profile in profiles {
+            if let Some(node) = CrawlNode::from_mini_profile(profile, depth) {
+                let id = node.urn_id.clone();
+                self.add_node(node);
+                self.add_edge(urn_id, &id);
+            }
+        }
+    }
+}
+
+impl CrawlNode {
+    pub fn from_connection(connection: Connection, depth: usize) -> Self {
+        CrawlNode {
+            urn_id: connection.urn_id,
+            public_id: Some(connection.public_id).filter(|p| !p.is_empty()),
+            name: connection.name,
+            headline: connection.headline,
+            distance: Some(connection.distance).filter(|d| !d.is_empty()),
+            depth,
+        }
+    }
+
+    pub fn from_mini_profile(profile: &MiniProfile, depth: usize) -> Option<Self> {
+        let name = [profile.first_name.as_deref(), profile.last_name.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        Some(CrawlNode {
+            urn_id: profile.urn()?.id,
+            public_id: profile.public_identifier.clone(),
+            name: Some(name).filter(|n| !n.is_empty()),
+            headline: profile.occupation.clone(),
+            distance: None,
+            depth,
+        })
+    }
+
+    /// The company named in a "Title at Company" headline
+    pub fn company(&self) -> Option<String> {
+        let headline = self.headline.as_deref()?;
+        let lower = headline.to_ascii_lowercase();
+        let index = [" at ", " @ "]
+            .iter()
+            .filter_map(|separator| lower.rfind(separator).map(|i| i + separator.len()))
+            .max()?;
+        let company = headline[index..].split(['|', ',']).next()?.trim();
+        (!company.is_empty()).then(|| company.to_string())
+    }
+}
+
+/// Everything needed to resume a crawl
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CrawlState {
+    graph: CrawlGraph,
+    /// Profiles still to expand, with their depth
+    frontier: VecDeque<(String, usize)>,
+    expanded: BTreeSet<String>,
+    requests_used: usize,
+}
+
+/// Breadth-first walk of the connection graph from seed profiles.
+///
+/// Each expanded profile costs one or more search requests, all of which go
+/// through the client's usual request pacing. The crawler counts only its own
+/// requests and checks the budget before each one, so it never exceeds it; a
+/// profile cut short by the budget keeps the connections already fetched.
+/// The crawl stops when the frontier is empty or the budget is spent; with a
+/// checkpoint file set, progress is saved after every expansion and a later
+/// run resumes from it.
+pub struct Crawler {
+    inner: LinkedinInner,
+    seeds: Vec<UniformResourceName>,
+    max_depth: usize,
+    fan_out: usize,
+    request_budget: usize,
+    checkpoint: Option<PathBuf>,
+}
+
+impl Crawler {
+    pub(crate) fn new(inner: LinkedinInner, seeds: Vec<UniformResourceName>) -> Self {
+        Self {
+            inner,
+            seeds,
+            max_depth: DEFAULT_MAX_DEPTH,
+            fan_out: DEFAULT_FAN_OUT,
+            request_budget: DEFAULT_REQUEST_BUDGET,
+            checkpoint: None,
+        }
+    }
+
+    /// Expand profiles up to this many hops from a seed
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Follow at most this many connections of each profile
+    pub fn fan_out(mut self, fan_out: usize) -> Self {
+        self.fan_out = fan_out.max(1);
+        self
+    }
+
+    /// Stop once this many API requests have been made, across resumed runs
+    pub fn request_budget(mut self, request_budget: usize) -> Self {
+        self.request_budget = request_budget;
+        self
+    }
+
+    /// Save progress to this file and resume from it if it exists
+    pub fn checkpoint(mut self, path: impl Into<PathBuf>) -> Self {
+        self.checkpoint = Some(path.into());
+        self
+    }
+
+    pub async fn run(&self) -> Result<CrawlGraph, LinkedinError> {
+        let mut state = match self.load_checkpoint()? {
+            Some(state) => state,
+            None => self.seed_state(),
+        };
+
+        while let Some((urn_id, depth)) = state.frontier.pop_front() {
+            if state.expanded.contains(&urn_id) || depth >= self.max_depth {
+                continue;
+            }
+            if state.requests_used >= self.request_budget {
+                state.frontier.push_front((urn_id, depth));
+                break;
+            }
+
+            let connections = match self.connections_of(&urn_id, &mut state.requests_used).await {
+                Ok(connections) => connections,
+                Err(e) => {
+                    // Keep the profile queued so a resumed crawl retries it
+                    state.frontier.push_front((urn_id, depth));
+                    self.save_checkpoint(&state)?;
+                    return Err(e);
+                }
+            };
+
+            for connection in connections {
+                if connection.urn_id.is_empty() {
+                    continue;
+                }
+                state.graph.add_edge(&urn_id, &connection.urn_id);
+                if !state.expanded.contains(&connection.urn_id) {
+                    state
+                        .frontier
+                        .push_back((connection.urn_id.clone(), depth + 1));
+                }
+                state
+                    .graph
+                    .add_node(CrawlNode::from_connection(connection, depth + 1));
+            }
+            state.expanded.insert(urn_id);
+            self.save_checkpoint(&state)?;
+        }
+
+        self.save_checkpoint(&state)?;
+        Ok(state.graph)
+    }
+
+    /// Page through a profile's connections up to the fan-out, charging every
+    /// request, failed or not, and stopping once the budget is spent.
+    async fn connections_of(
+        &self,
+        urn_id: &str,
+        requests_used: &mut usize,
+    ) -> Result<Vec<Connection>, LinkedinError> {
+        let mut connections = vec![];
+        let mut start = 0;
+
+        while connections.len() < self.fan_out && *requests_used < self.request_budget {
+            let count = (self.fan_out - connections.len()).min(SEARCH_PAGE_SIZE);
+            let page = self
+                .inner
+                .get_profile_connections_page(urn_id, start, count)
+                .await;
+            *requests_used += 1;
+
+            let (page, returned) = page?;
+            connections.extend(page);
+            start += returned;
+            if returned < count {
+                break;
+            }
+        }
+
+        connections.truncate(self.fan_out);
+        Ok(connections)
+    }
+
+    fn seed_state(&self) -> CrawlState {
+        let mut state = CrawlState::default();
+        for seed in &self.seeds {
+            state.graph.add_node(CrawlNode {
+                urn_id: seed.id.clone(),
+                public_id: None,
+                name: None,
+                headline: None,
+                distance: None,
+                depth: 0,
+            });
+            state.frontier.push_back((seed.id.clone(), 0));
+        }
+        state
+    }
+
+    fn load_checkpoint(&self) -> Result<Option<CrawlState>, LinkedinError> {
+        let Some(path) = &self.checkpoint else {
+            return Ok(None);
+        };
+        if !path.exists() {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::from_reader(BufReader::new(File::open(
+            path,
+        )?))?))
+    }
+
+    fn save_checkpoint(&self, state: &CrawlState) -> Result<(), LinkedinError> {
+        let Some(path) = &self.checkpoint else {
+            return Ok(());
+        };
+        write_atomically(path, |writer| Ok(serde_json::to_writer(writer, state)?))
+    }
+}
@@ -42,3 +42,14 @@ pub enum LinkedinError {
     #[error("Header to string error: {0}")]
     HeaderToStr(#[from] reqwest::header::ToStrError),
 }
+
+impl LinkedinError {
+    /// Map an unsuccessful HTTP status onto the matching error variant.
+    pub(crate) fn from_status(status: reqwest::StatusCode) -> Self {
+        match status.as_u16() {
+            401 | 403 => LinkedinError::Unauthorized(format!("status {status}")),
+            429 => LinkedinError::RateLimit,
+            _ => LinkedinError::RequestFailed(format!("status {status}")),
+        }
+    }
+}
@@ -0,0 +1,555 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::inbox::{Inbox, InboxFilter};
+use crate::linkedin::LinkedinInner;
+use crate::types::{Conversation, Message, MessageContent, MiniProfile};
+use crate::utils::{datetime_from_millis, write_atomically};
+use crate::LinkedinError;
+
+const STATE_FILE: &str = ".linkedin-export.json";
+const MBOX_FILE: &str = "inbox.mbox";
+
+/// Output format of an [`Exporter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// A single RFC 4155 mbox file with one entry per message event
+    Mbox,
+    /// One JSON document per conversation
+    Json,
+    /// One Markdown transcript per conversation
+    Markdown,
+}
+
+/// A conversation and its messages, as written by [`ExportFormat::Json`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConversationExport {
+    pub conversation: Conversation,
+    pub messages: Vec<Message>,
+}
+
+/// What a single export run wrote
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExportReport {
+    pub conversations: usize,
+    pub messages: usize,
+}
+
+/// Newest exported message per conversation, in epoch milliseconds
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ExportState {
+    conversations: HashMap<String, i64>,
+    /// An append that started but may not have finished
+    #[serde(default)]
+    pending: Option<PendingWrite>,
+}
+
+/// Length of a file before an append, so an interrupted append can be undone
+#[derive(Debug, Serialize, Deserialize)]
+struct PendingWrite {
+    path: PathBuf,
+    length: u64,
+}
+
+/// Writes every conversation and its history to a directory.
+///
+/// Progress is recorded in the directory after each conversation, so a later
+/// run only appends messages newer than the last export.
+pub struct Exporter {
+    inner: LinkedinInner,
+    directory: PathBuf,
+    format: ExportFormat,
+    filter: InboxFilter,
+}
+
+impl Exporter {
+    pub(crate) fn new(inner: LinkedinInner, directory: PathBuf, format: ExportFormat) -> Self {
+        Self {
+            inner,
+            directory,
+            format,
+            filter: InboxFilter::All,
+        }
+    }
+
+    /// Only export conversations in this inbox view
+    pub fn filter(mut self, filter: InboxFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub async fn run(&self) -> Result<ExportReport, LinkedinError> {
+        let mut archive = ExportArchive::open(&self.directory, self.format)?;
+        let mut report = ExportReport::default();
+
+        let mut inbox = Inbox::new(self.inner.clone()).filter(self.filter.clone());
+        while let Some(conversation) = inbox.next().await? {
+            if !archive.needs_export(&conversation) {
+                continue;
+            }
+
+            let since = archive.exported_until(&conversation.id);
+            let messages = self
+                .inner
+                .get_conversation_since(&conversation.id, since, None)
+                .await?;
+            let written = archive.append(&conversation, &messages)?;
+            if written > 0 {
+                report.conversations += 1;
+                report.messages += written;
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+/// An export directory: the output files and what earlier runs exported.
+///
+/// Appends are journalled in the state file, so a run interrupted mid-write
+/// is rolled back when the archive is next opened rather than leaving
+/// duplicated or partial messages behind.
+pub struct ExportArchive {
+    directory: PathBuf,
+    format: ExportFormat,
+    state: ExportState,
+}
+
+impl ExportArchive {
+    pub fn open(
+        directory: impl Into<PathBuf>,
+        format: ExportFormat,
+    ) -> Result<Self, LinkedinError> {
+        let directory = directory.into();
+        fs::create_dir_all(&directory)?;
+
+        let path = directory.join(STATE_FILE);
+        let state = if path.exists() {
+            serde_json::from_reader(BufReader::new(File::open(path)?))?
+        } else {
+            ExportState::default()
+        };
+
+        let mut archive = Self {
+            directory,
+            format,
+            state,
+        };
+        if let Some(pending) = archive.state.pending.take() {
+            rollback(&pending)?;
+            archive.save_state()?;
+        }
+        Ok(archive)
+    }
+
+    /// Creation time of the newest message exported from a conversation
+    pub fn exported_until(&self, conversation_id: &str) -> Option<OffsetDateTime> {
+        self.state
+            .conversations
+            .get(conversation_id)
+            .copied()
+            .and_then(datetime_from_millis)
+    }
+
+    /// Whether a conversation may have messages that aren't exported yet.
+    /// Conversations without a known last activity are always checked.
+    pub fn needs_export(&self, conversation: &Conversation) -> bool {
+        match (
+            self.exported_until(&conversation.id),
+            conversation.last_activity_at,
+        ) {
+            (Some(since), Some(last_activity)) => last_activity > since,
+            _ => true,
+        }
+    }
+
+    /// Write the messages of a conversation newer than its last export and
+    /// record progress. Returns how many messages were written.
+    pub fn append(
+        &mut self,
+        conversation: &Conversation,
+        messages: &[Message],
+    ) -> Result<usize, LinkedinError> {
+        let since = self.exported_until(&conversation.id);
+        let messages: Vec<Message> = messages
+            .iter()
+            .filter(|m| since.is_none_or(|since| m.created_at.is_some_and(|t| t > since)))
+            .cloned()
+            .collect();
+        if messages.is_empty() {
+            return Ok(0);
+        }
+
+        self.write(conversation, &messages)?;
+
+        if let Some(newest) = messages.iter().filter_map(|m| m.created_at).max() {
+            let millis = (newest.unix_timestamp_nanos() / 1_000_000) as i64;
+            self.state
+                .conversations
+                .insert(conversation.id.clone(), millis);
+        }
+        self.state.pending = None;
+        self.save_state()?;
+
+        Ok(messages.len())
+    }
+
+    fn write(
+        &mut self,
+        conversation: &Conversation,
+        messages: &[Message],
+    ) -> Result<(), LinkedinError> {
+        match self.format {
+            ExportFormat::Mbox => {
+                let mut file = self.begin_append(&self.directory.join(MBOX_FILE))?;
+                for message in messages {
+                    file.write_all(mbox_entry(conversation, message).as_bytes())?;
+                }
+                file.flush()?;
+            }
+            ExportFormat::Json => {
+                let path = self.thread_path(conversation, "json");
+                let mut export = if path.exists() {
+                    serde_json::from_reader(BufReader::new(File::open(&path)?))?
+                } else {
+                    ConversationExport {
+                        conversation: conversation.clone(),
+                        messages: vec![],
+                    }
+                };
+                export.conversation = conversation.clone();
+                // A rerun after a crash can see messages the file already has
+                let known: HashSet<_> = export
+                    .messages
+                    .iter()
+                    .filter_map(|m| m.entity_urn.clone())
+                    .collect();
+                export.messages.extend(
+                    messages
+                        .iter()
+                        .filter(|m| m.entity_urn.as_ref().is_none_or(|u| !known.contains(u)))
+                        .cloned(),
+                );
+
+                write_atomically(&path, |writer| {
+                    Ok(serde_json::to_writer_pretty(writer, &export)?)
+                })?;
+            }
+            ExportFormat::Markdown => {
+                let path = self.thread_path(conversation, "md");
+                let is_new = !path.exists();
+                let mut file = self.begin_append(&path)?;
+                if is_new {
+                    file.write_all(markdown_header(conversation).as_bytes())?;
+                }
+                for message in messages {
+                    file.write_all(markdown_entry(message).as_bytes())?;
+                }
+                file.flush()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Record the file's current length, then open it for appending.
+    fn begin_append(&mut self, path: &Path) -> Result<BufWriter<File>, LinkedinError> {
+        let length = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+        self.state.pending = Some(PendingWrite {
+            path: path.to_path_buf(),
+            length,
+        });
+        self.save_state()?;
+
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(BufWriter::new(file))
+    }
+
+    fn thread_path(&self, conversation: &Conversation, extension: &str) -> PathBuf {
+        let name: String = conversation
+            .id
+            .chars()
+            .map(|c| match c {
+                'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '=' => c,
+                _ => '_',
+            })
+            .collect();
+        self.directory.join(format!("{name}.{extension}"))
+    }
+
+    fn save_state(&self) -> Result<(), LinkedinError> {
+        write_atomically(&self.directory.join(STATE_FILE), |writer| {
+            Ok(serde_json::to_writer(writer, &self.state)?)
+        })
+    }
+}
+
+/// Undo an interrupted append. A file that didn't exist before is removed.
+fn rollback(pending: &PendingWrite) -> Result<(), LinkedinError> {
+    if !pending.path.exists() {
+        return Ok(());
+    }
+    if pending.length == 0 {
+        fs::remove_file(&pending.path)?;
+    } else {
+        OpenOptions::new()
+            .write(true)
+            .open(&pending.path)?
+            .set_len(pending.length)?;
+    }
+    Ok(())
+}
+
+/// Format one message as an mbox entry: the `From ` separator line, headers
+/// naming the sender and participants, and an mboxrd-quoted body.
+pub fn mbox_entry(conversation: &Conversation, message: &Message) -> String {
+    let created_at = message.created_at.unwrap_or(OffsetDateTime::UNIX_EPOCH);
+    let sender = message.sender.as_ref();
+    let sender_address = sender
+        .map(address)
+        .unwrap_or_else(|| "unknown@linkedin.com".into());
+
+    let mut entry = format!("From {} {}\n", sender_address, asctime(created_at));
+    entry.push_str(&format!(
+        "From: {}\n",
+        mailbox(sender.map(display_name).as_deref(), &sender_address)
+    ));
+    let recipients: Vec<String> = conversation
+        .participants
+        .iter()
+        .map(|p| mailbox(Some(&display_name(p)), &address(p)))
+        .collect();
+    if !recipients.is_empty() {
+        entry.push_str(&format!("To: {}\n", recipients.join(", ")));
+    }
+    entry.push_str(&format!("Date: {}\n", rfc2822(created_at)));
+    entry.push_str(&format!(
+        "Subject: {}\n",
+        encode_header(&subject(conversation))
+    ));
+    if let Some(urn) = &message.entity_urn {
+        entry.push_str(&format!("Message-ID: <{}@linkedin.com>\n", msg_id(&urn.id)));
+    }
+    entry.push_str(&format!("X-LinkedIn-Conversation: {}\n", conversation.id));
+    entry.push_str("Content-Type: text/plain; charset=utf-8\n\n");
+
+    for line in body_text(message).lines() {
+        // mboxrd: quote any line that could be mistaken for a separator
+        if line.trim_start_matches('>').starts_with("From ") {
+            entry.push('>');
+        }
+        entry.push_str(line);
+        entry.push('\n');
+    }
+    entry.push('\n');
+
+    entry
+}
+
+/// The title and participant list at the top of a Markdown transcript
+pub fn markdown_header(conversation: &Conversation) -> String {
+    let mut header = format!("# {}\n\n", subject(conversation));
+    for participant in &conversation.participants {
+        header.push_str(&format!("- {}\n", display_name(participant)));
+    }
+    header.push('\n');
+    header
+}
+
+/// One message of a Markdown transcript
+pub fn markdown_entry(message: &Message) -> String {
+    let sender = message
+        .sender
+        .as_ref()
+        .map(display_name)
+        .unwrap_or_else(|| "Unknown".into());
+    let time = message
+        .created_at
+        .map(|t| format!(" — {}", rfc2822(t)))
+        .unwrap_or_default();
+
+    let mut entry = format!("**{sender}**{time}\n\n");
+    match &message.content {
+        MessageContent::Message {
+            body, attachments, ..
+        } => {
+            if !body.text.is_empty() {
+                entry.push_str(&format!("{}\n\n", body.text));
+            }
+            for attachment in attachments {
+                let name = attachment.name.as_deref().unwrap_or("attachment");
+                match &attachment.url {
+                    Some(url) => entry.push_str(&format!("- [{name}]({url})\n")),
+                    None => entry.push_str(&format!("- {name}\n")),
+                }
+            }
+            if !attachments.is_empty() {
+                entry.push('\n');
+            }
+        }
+        _ => entry.push_str(&format!("_{}_\n\n", body_text(message))),
+    }
+    entry
+}
+
+fn body_text(message: &Message) -> String {
+    match &message.content {
+        MessageContent::Message {
+            body, attachments, ..
+        } => {
+            let mut text = body.text.clone();
+            for attachment in attachments {
+                text.push_str(&format!(
+                    "\n[Attachment: {}]",
+                    attachment.name.as_deref().unwrap_or("unnamed")
+                ));
+            }
+            text
+        }
+        MessageContent::ParticipantChange { added, removed } => {
+            let names = |profiles: &[MiniProfile]| {
+                profiles
+                    .iter()
+                    .map(display_name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            let mut changes = vec![];
+            if !added.is_empty() {
+                changes.push(format!("Added {}", names(added)));
+            }
+            if !removed.is_empty() {
+                changes.push(format!("Removed {}", names(removed)));
+            }
+            changes.join("; ")
+        }
+        MessageContent::Other { kind } => format!("[{kind}]"),
+    }
+}
+
+fn subject(conversation: &Conversation) -> String {
+    conversation.name.clone().unwrap_or_else(|| {
+        let names: Vec<String> = conversation.participants.iter().map(display_name).collect();
+        format!("LinkedIn conversation with {}", names.join(", "))
+    })
+}
+
+fn display_name(profile: &MiniProfile) -> String {
+    let name = [profile.first_name.as_deref(), profile.last_name.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if name.is_empty() {
+        profile
+            .public_identifier
+            .clone()
+            .unwrap_or_else(|| "Unknown".into())
+    } else {
+        name
+    }
+}
+
+/// A stable, synthetic address for a member
+fn address(profile: &MiniProfile) -> String {
+    let local = profile
+        .public_identifier
+        .clone()
+        .or_else(|| profile.urn().map(|urn| urn.id))
+        .unwrap_or_else(|| "unknown".into());
+    format!("{local}@linkedin.com")
+}
+
+fn mailbox(name: Option<&str>, address: &str) -> String {
+    match name {
+        Some(name) if !name.is_ascii() => format!("{} <{address}>", encode_header(name)),
+        Some(name) => format!("\"{}\" <{address}>", name.replace('"', "'")),
+        None => format!("<{address}>"),
+    }
+}
+
+/// RFC 2047 Q-encode a header value that isn't plain ASCII, split into
+/// encoded words of at most 75 characters.
+fn encode_header(value: &str) -> String {
+    const PREFIX: &str = "=?utf-8?Q?";
+    const MAX_WORD: usize = 75 - PREFIX.len() - "?=".len();
+
+    if value.is_ascii() {
+        return value.to_string();
+    }
+
+    let mut words = vec![];
+    let mut word = String::new();
+    for c in value.chars() {
+        let mut buffer = [0; 4];
+        let encoded: String = match c {
+            ' ' => "_".into(),
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '!' | '*' | '+' | '-' | '/' => c.into(),
+            _ => c
+                .encode_utf8(&mut buffer)
+                .bytes()
+                .map(|b| format!("={b:02X}"))
+                .collect(),
+        };
+        // Never split a character's bytes across words
+        if word.len() + encoded.len() > MAX_WORD {
+            words.push(format!("{PREFIX}{word}?="));
+            word.clear();
+        }
+        word.push_str(&encoded);
+    }
+    words.push(format!("{PREFIX}{word}?="));
+    words.join(" ")
+}
+
+/// Percent-escape the characters a message id can't contain, such as the
+/// parentheses and comma of a message event id.
+fn msg_id(id: &str) -> String {
+    id.bytes()
+        .map(|b| match b {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'=' | b'+' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+fn short_name(name: impl ToString) -> String {
+    name.to_string().chars().take(3).collect()
+}
+
+/// `Sat Jan  3 01:05:34 1996`, as used on mbox separator lines
+fn asctime(time: OffsetDateTime) -> String {
+    let time = time.to_offset(time::UtcOffset::UTC);
+    format!(
+        "{} {} {:>2} {:02}:{:02}:{:02} {}",
+        short_name(time.weekday()),
+        short_name(time.month()),
+        time.day(),
+        time.hour(),
+        time.minute(),
+        time.second(),
+        time.year()
+    )
+}
+
+/// `Sat, 03 Jan 1996 01:05:34 +0000`
+fn rfc2822(time: OffsetDateTime) -> String {
+    let time = time.to_offset(time::UtcOffset::UTC);
+    format!(
+        "{}, {:02} {} {} {:02}:{:02}:{:02} +0000",
+        short_name(time.weekday()),
+        time.day(),
+        short_name(time.month()),
+        time.year(),
+        time.hour(),
+        time.minute(),
+        time.second()
+    )
+}
@@ -0,0 +1,135 @@
+use std::collections::{HashSet, VecDeque};
+
+use time::OffsetDateTime;
+
+use crate::linkedin::LinkedinInner;
+use crate::types::{FeedUpdate, UniformResourceName};
+use crate::LinkedinError;
+
+const DEFAULT_PAGE_SIZE: usize = 25;
+const MAX_EMPTY_PAGES: usize = 5;
+
+/// Reader over the authenticated user's home feed.
+///
+/// Updates are deduplicated by activity URN for the lifetime of the reader,
+/// so repeated calls to [`HomeFeed::poll`] only return items not seen before.
+pub struct HomeFeed {
+    inner: LinkedinInner,
+    filter: FeedFilter,
+    page_size: usize,
+    buffer: VecDeque<FeedUpdate>,
+    start: usize,
+    pagination_token: Option<String>,
+    exhausted: bool,
+}
+
+impl HomeFeed {
+    pub(crate) fn new(inner: LinkedinInner) -> Self {
+        Self {
+            inner,
+            filter: FeedFilter::default(),
+            page_size: DEFAULT_PAGE_SIZE,
+            buffer: VecDeque::new(),
+            start: 0,
+            pagination_token: None,
+            exhausted: false,
+        }
+    }
+
+    /// Skip updates published before `since`
+    pub fn since(mut self, since: OffsetDateTime) -> Self {
+        self.filter.since = Some(since);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Treat these activity URNs as already seen, e.g. when resuming from a previous run
+    pub fn with_seen(mut self, seen: impl IntoIterator<Item = UniformResourceName>) -> Self {
+        self.filter.seen.extend(seen);
+        self
+    }
+
+    /// Activity URNs returned so far
+    pub fn seen(&self) -> impl Iterator<Item = &UniformResourceName> {
+        self.filter.seen.iter()
+    }
+
+    /// Returns the next update further down the feed, or `None` once the feed
+    /// (or the `since` window) is exhausted.
+    pub async fn next(&mut self) -> Result<Option<FeedUpdate>, LinkedinError> {
+        let mut empty_pages = 0;
+        while self.buffer.is_empty() && !self.exhausted && empty_pages < MAX_EMPTY_PAGES {
+            let (updates, pagination_token) = self
+                .inner
+                .get_feed_page(self.start, self.page_size, self.pagination_token.as_deref())
+                .await?;
+
+            // The chronological feed is ordered newest first, so a page with
+            // nothing inside the window means everything after it is older.
+            if updates.is_empty() || updates.iter().all(|u| self.filter.is_too_old(u)) {
+                self.exhausted = true;
+            }
+
+            self.start += updates.len();
+            self.pagination_token = pagination_token;
+
+            let fresh = self.filter.take_fresh(updates);
+            if fresh.is_empty() {
+                empty_pages += 1;
+            }
+            self.buffer.extend(fresh);
+        }
+
+        Ok(self.buffer.pop_front())
+    }
+
+    /// Re-reads the top of the feed and returns updates not seen before.
+    pub async fn poll(&mut self) -> Result<Vec<FeedUpdate>, LinkedinError> {
+        let (updates, _) = self.inner.get_feed_page(0, self.page_size, None).await?;
+
+        Ok(self.filter.take_fresh(updates))
+    }
+}
+
+/// The `since` window and seen activity URNs a [`HomeFeed`] filters pages by
+#[derive(Debug, Clone, Default)]
+pub struct FeedFilter {
+    since: Option<OffsetDateTime>,
+    seen: HashSet<UniformResourceName>,
+}
+
+impl FeedFilter {
+    pub fn new(since: Option<OffsetDateTime>) -> Self {
+        Self {
+            since,
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Whether an update was published before `since`. Updates without a
+    /// publish time are never too old.
+    pub fn is_too_old(&self, update: &FeedUpdate) -> bool {
+        match (self.since, update.post().published_at) {
+            (Some(since), Some(published_at)) => published_at < since,
+            _ => false,
+        }
+    }
+
+    /// Keep the updates inside the window that weren't seen before, marking them seen.
+    pub fn take_fresh(&mut self, updates: Vec<FeedUpdate>) -> Vec<FeedUpdate> {
+        updates
+            .into_iter()
+            .filter(|update| {
+                !self.is_too_old(update)
+                    && match &update.post().urn {
+                        Some(urn) => self.seen.insert(urn.clone()),
+                        None => true,
+                    }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,216 @@
+use std::fs;
+use std::path::Path;
+
+use crate::crawler::{CrawlGraph, CrawlNode};
+use crate::LinkedinError;
+
+/// File format for [`write_graph`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    GraphMl,
+    Gexf,
+    Dot,
+    /// `nodes.csv` and `edges.csv`, written into a directory
+    Csv,
+}
+
+/// Write a graph to `path`. For [`GraphFormat::Csv`], `path` is a directory.
+pub fn write_graph(
+    graph: &CrawlGraph,
+    format: GraphFormat,
+    path: &Path,
+) -> Result<(), LinkedinError> {
+    match format {
+        GraphFormat::GraphMl => fs::write(path, to_graphml(graph))?,
+        GraphFormat::Gexf => fs::write(path, to_gexf(graph))?,
+        GraphFormat::Dot => fs::write(path, to_dot(graph))?,
+        GraphFormat::Csv => {
+            fs::create_dir_all(path)?;
+            fs::write(path.join("nodes.csv"), to_csv_nodes(graph))?;
+            fs::write(path.join("edges.csv"), to_csv_edges(graph))?;
+        }
+    }
+    Ok(())
+}
+
+/// Node attributes shared by every format, in column order
+fn attributes(node: &CrawlNode) -> [(&'static str, String); 5] {
+    [
+        ("name", node.name.clone().unwrap_or_default()),
+        ("headline", node.headline.clone().unwrap_or_default()),
+        ("company", node.company().unwrap_or_default()),
+        ("distance", node.distance.clone().unwrap_or_default()),
+        ("depth", node.depth.to_string()),
+    ]
+}
+
+pub fn to_graphml(graph: &CrawlGraph) -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+    );
+    for (key, kind) in [
+        ("name", "string"),
+        ("headline", "string"),
+        ("company", "string"),
+        ("distance", "string"),
+        ("depth", "int"),
+    ] {
+        xml.push_str(&format!(
+            "  <key id=\"{key}\" for=\"node\" attr.name=\"{key}\" attr.type=\"{kind}\"/>\n"
+        ));
+    }
+    xml.push_str("  <graph id=\"linkedin\" edgedefault=\"undirected\">\n");
+
+    for node in graph.nodes.values() {
+        xml.push_str(&format!("    <node id=\"{}\">\n", escape_xml(&node.urn_id)));
+        for (key, value) in attributes(node) {
+            if !value.is_empty() {
+                xml.push_str(&format!(
+                    "      <data key=\"{key}\">{}</data>\n",
+                    escape_xml(&value)
+                ));
+            }
+        }
+        xml.push_str("    </node>\n");
+    }
+    for edge in &graph.edges {
+        xml.push_str(&format!(
+            "    <edge source=\"{}\" target=\"{}\"/>\n",
+            escape_xml(&edge.source),
+            escape_xml(&edge.target)
+        ));
+    }
+
+    xml.push_str("  </graph>\n</graphml>\n");
+    xml
+}
+
+pub fn to_gexf(graph: &CrawlGraph) -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <gexf xmlns=\"http://gexf.net/1.3\" version=\"1.3\">\n  \
+         <graph mode=\"static\" defaultedgetype=\"undirected\">\n    \
+         <attributes class=\"node\">\n",
+    );
+    for (id, (title, kind)) in [
+        ("headline", "string"),
+        ("company", "string"),
+        ("distance", "string"),
+        ("depth", "integer"),
+    ]
+    .iter()
+    .enumerate()
+    {
+        xml.push_str(&format!(
+            "      <attribute id=\"{id}\" title=\"{title}\" type=\"{kind}\"/>\n"
+        ));
+    }
+    xml.push_str("    </attributes>\n    <nodes>\n");
+
+    for node in graph.nodes.values() {
+        let label = node.name.as_deref().unwrap_or(&node.urn_id);
+        xml.push_str(&format!(
+            "      <node id=\"{}\" label=\"{}\">\n        <attvalues>\n",
+            escape_xml(&node.urn_id),
+            escape_xml(label)
+        ));
+        // The name is the label; the rest are attributes 0..
+        for (id, (_, value)) in attributes(node).into_iter().skip(1).enumerate() {
+            if !value.is_empty() {
+                xml.push_str(&format!(
+                    "          <attvalue for=\"{id}\" value=\"{}\"/>\n",
+                    escape_xml(&value)
+                ));
+            }
+        }
+        xml.push_str("        </attvalues>\n      </node>\n");
+    }
+    xml.push_str("    </nodes>\n    <edges>\n");
+    for (id, edge) in graph.edges.iter().enumerate() {
+        xml.push_str(&format!(
+            "      <edge id=\"{id}\" source=\"{}\" target=\"{}\"/>\n",
+            escape_xml(&edge.source),
+            escape_xml(&edge.target)
+        ));
+    }
+
+    xml.push_str("    </edges>\n  </graph>\n</gexf>\n");
+    xml
+}
+
+pub fn to_dot(graph: &CrawlGraph) -> String {
+    let mut dot = String::from("graph linkedin {\n");
+    for node in graph.nodes.values() {
+        let mut attrs = vec![];
+        for (key, value) in attributes(node) {
+            let key = if key == "name" { "label" } else { key };
+            if !value.is_empty() {
+                attrs.push(format!("{key}=\"{}\"", escape_dot(&value)));
+            }
+        }
+        dot.push_str(&format!(
+            "  \"{}\" [{}];\n",
+            escape_dot(&node.urn_id),
+            attrs.join(", ")
+        ));
+    }
+    for edge in &graph.edges {
+        dot.push_str(&format!(
+            "  \"{}\" -- \"{}\";\n",
+            escape_dot(&edge.source),
+            escape_dot(&edge.target)
+        ));
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// One row per node: `id,name,headline,company,distance,depth`
+pub fn to_csv_nodes(graph: &CrawlGraph) -> String {
+    let mut csv = String::from("id,name,headline,company,distance,depth\n");
+    for node in graph.nodes.values() {
+        let mut row = vec![escape_csv(&node.urn_id)];
+        row.extend(attributes(node).iter().map(|(_, value)| escape_csv(value)));
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// One row per edge: `source,target`
+pub fn to_csv_edges(graph: &CrawlGraph) -> String {
+    let mut csv = String::from("source,target\n");
+    for edge in &graph.edges {
+        csv.push_str(&format!(
+            "{},{}\n",
+            escape_csv(&edge.source),
+            escape_csv(&edge.target)
+        ));
+    }
+    csv
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn escape_dot(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
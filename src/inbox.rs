@@ -0,0 +1,150 @@
+use std::collections::{HashSet, VecDeque};
+
+use time::{Duration, OffsetDateTime};
+
+use crate::linkedin::LinkedinInner;
+use crate::types::{Conversation, ConversationType, UniformResourceName};
+use crate::LinkedinError;
+
+const MAX_PAGES: usize = 200;
+
+/// Which conversations an [`Inbox`] walks
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InboxFilter {
+    #[default]
+    All,
+    Unread,
+    Archived,
+    /// InMail and sponsored messages
+    InMail,
+    /// Conversations that include this profile
+    Participant(UniformResourceName),
+}
+
+impl InboxFilter {
+    /// Whether a conversation belongs in this view. LinkedIn applies the filter
+    /// server-side too; this guards against it returning stray threads.
+    pub fn matches(&self, conversation: &Conversation) -> bool {
+        match self {
+            InboxFilter::All => true,
+            InboxFilter::Unread => !conversation.read || conversation.unread_count > 0,
+            InboxFilter::Archived => conversation.archived,
+            InboxFilter::InMail => matches!(
+                conversation.conversation_type,
+                ConversationType::InMail | ConversationType::Sponsored
+            ),
+            InboxFilter::Participant(urn) => conversation.participants.iter().any(|participant| {
+                participant
+                    .entity_urn
+                    .as_deref()
+                    .and_then(|u| UniformResourceName::parse(u).ok())
+                    .is_some_and(|u| u.id == urn.id)
+            }),
+        }
+    }
+}
+
+/// Reader over the whole messaging inbox, paging backwards by `createdBefore`.
+pub struct Inbox {
+    inner: LinkedinInner,
+    filter: InboxFilter,
+    created_before: Option<OffsetDateTime>,
+    seen: HashSet<String>,
+    buffer: VecDeque<Conversation>,
+    pages: usize,
+    exhausted: bool,
+}
+
+impl Inbox {
+    pub(crate) fn new(inner: LinkedinInner) -> Self {
+        Self {
+            inner,
+            filter: InboxFilter::All,
+            created_before: None,
+            seen: HashSet::new(),
+            buffer: VecDeque::new(),
+            pages: 0,
+            exhausted: false,
+        }
+    }
+
+    pub fn filter(mut self, filter: InboxFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Start from conversations last active before this time
+    pub fn created_before(mut self, created_before: OffsetDateTime) -> Self {
+        self.created_before = Some(created_before);
+        self
+    }
+
+    /// Returns the next conversation, or `None` once the inbox is exhausted.
+    pub async fn next(&mut self) -> Result<Option<Conversation>, LinkedinError> {
+        while self.buffer.is_empty() && !self.exhausted {
+            let page = self.fetch_page().await?;
+            self.buffer.extend(page);
+        }
+
+        Ok(self.buffer.pop_front())
+    }
+
+    /// Returns the next page of conversations, or `None` once the inbox is exhausted.
+    pub async fn next_page(&mut self) -> Result<Option<Vec<Conversation>>, LinkedinError> {
+        if !self.buffer.is_empty() {
+            return Ok(Some(self.buffer.drain(..).collect()));
+        }
+        while !self.exhausted {
+            let page = self.fetch_page().await?;
+            if !page.is_empty() {
+                return Ok(Some(page));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn fetch_page(&mut self) -> Result<Vec<Conversation>, LinkedinError> {
+        let page = self
+            .inner
+            .get_conversations_page(&self.filter, self.created_before)
+            .await?;
+        self.pages += 1;
+
+        let oldest = page.iter().filter_map(|c| c.last_activity_at).min();
+
+        // Pages overlap on purpose, so only keep conversations not seen yet
+        let mut found_new = false;
+        let fresh: Vec<Conversation> = page
+            .into_iter()
+            .filter(|conversation| {
+                let new = self.seen.insert(conversation.id.clone());
+                found_new |= new;
+                new
+            })
+            .collect();
+
+        match oldest {
+            None => self.exhausted = true,
+            Some(oldest) if !found_new => {
+                // A millisecond holding more than a page of conversations would
+                // repeat forever; step strictly past it once before stopping
+                if self.created_before == Some(oldest) {
+                    self.exhausted = true;
+                }
+                self.created_before = Some(oldest);
+            }
+            // Include the oldest millisecond again: other conversations sharing
+            // it may not have fit on this page
+            Some(oldest) => self.created_before = Some(oldest + Duration::milliseconds(1)),
+        }
+        if self.pages >= MAX_PAGES {
+            self.exhausted = true;
+        }
+
+        Ok(fresh
+            .into_iter()
+            .filter(|conversation| self.filter.matches(conversation))
+            .collect())
+    }
+}
@@ -5,15 +5,17 @@
 //! # Example
 //!
 //! ```no_run
-//! use linkedin_api_rs::Linkedin;
+//! use linkedin_api::{types::Identity, Linkedin};
 //! use std::env;
 //!
 //! #[tokio::main]
-//! async fn main() -> Result<(), linkedin_api_rs::LinkedinError> {
-//!     let username = env::var("LINKEDIN_USERNAME").unwrap();
-//!     let password = env::var("LINKEDIN_PASSWORD").unwrap();
+//! async fn main() -> Result<(), linkedin_api::LinkedinError> {
+//!     let identity = Identity {
+//!         authentication_token: env::var("LINKEDIN_LI_AT").unwrap(),
+//!         session_cookie: env::var("LINKEDIN_JSESSIONID").unwrap(),
+//!     };
 //!
-//!     let api = Linkedin::new(&username, &password, false).await?;
+//!     let api = Linkedin::new(&identity, false).await?;
 //!
 //!     let profile = api.get_profile("billy-g").await?;
 //!
@@ -21,22 +23,45 @@
 //! }
 //! ```
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use time::OffsetDateTime;
 
+use crate::crawler::Crawler;
 pub use crate::error::LinkedinError;
-use crate::types::{ProfileView, SearchPeopleParams};
+use crate::export::{ExportFormat, Exporter};
+use crate::feed::HomeFeed;
+use crate::inbox::Inbox;
+use crate::realtime::Subscription;
+use crate::triage::{TriageReport, TriageRules};
+use crate::types::{
+    Comment, ConnectionSort, ConversationAction, ConversationUpdate, FeedUpdate, FollowKind,
+    FollowedEntity, InvitationAction, InvitationResult, JobApplicantInsights, JobPosting,
+    JobSkillMatch, Message, MiniProfile, NewMessage, NewPost, OwnConnection, ProfileView, Reaction,
+    ReactionType, SearchPeopleParams, SeenReceipt, SentInvitation, SentMessage, SharedConnections,
+};
 use crate::{
     linkedin::LinkedinInner,
     types::{
         Company, Connection, ContactInfo, Conversation, ConversationDetails, Identity, Invitation,
-        MemberBadges, NetworkInfo, PersonSearchResult, Profile, School, Skill, UniformResourceName,
+        MemberBadges, NetworkInfo, PersonSearchResult, School, Skill, UniformResourceName,
     },
 };
 
 pub mod client;
+pub mod crawler;
 pub mod error;
+pub mod export;
+pub mod feed;
+pub mod graph;
+pub mod inbox;
 pub mod linkedin;
+pub mod realtime;
+pub mod triage;
 pub mod types;
 pub mod utils;
 
@@ -48,7 +73,8 @@ pub struct Linkedin {
 
 impl UniformResourceName {
     pub fn parse(urn: &str) -> Result<Self, LinkedinError> {
-        let parts: Vec<&str> = urn.split(':').collect();
+        // The id may itself contain colons, e.g. "urn:li:fs_updateV2:(urn:li:activity:1,…)"
+        let parts: Vec<&str> = urn.splitn(4, ':').collect();
         if parts.len() < 4 {
             return Err(LinkedinError::InvalidInput(format!(
                 "Not enough components in URN: {urn}"
@@ -80,6 +106,33 @@ impl AsRef<str> for UniformResourceName {
     }
 }
 
+impl fmt::Display for UniformResourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "urn:li:{}:{}", self.namespace, self.id)
+    }
+}
+
+impl FromStr for UniformResourceName {
+    type Err = LinkedinError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Serialize for UniformResourceName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for UniformResourceName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        Self::parse(&raw).map_err(serde::de::Error::custom)
+    }
+}
+
 impl Linkedin {
     /// Create a new LinkedIn client and authenticate.
     pub async fn new(identity: &Identity, refresh_cookies: bool) -> Result<Self, LinkedinError> {
@@ -108,6 +161,48 @@ impl Linkedin {
         self.inner.get_profile_connections(urn_id).await
     }
 
+    /// Returns the connections the current user shares with a profile.
+    pub async fn get_shared_connections(
+        &self,
+        public_id: &str,
+    ) -> Result<SharedConnections, LinkedinError> {
+        self.inner
+            .get_shared_connections(Some(public_id), None)
+            .await
+    }
+
+    /// Returns the connections the current user shares with a profile, by URN.
+    pub async fn get_shared_connections_by_urn(
+        &self,
+        urn: &UniformResourceName,
+    ) -> Result<SharedConnections, LinkedinError> {
+        self.inner.get_shared_connections(None, Some(urn)).await
+    }
+
+    /// Returns a breadth-first crawler over the connection graph, starting
+    /// from `seeds`.
+    pub fn crawler(&self, seeds: &[UniformResourceName]) -> Crawler {
+        Crawler::new(self.inner.clone(), seeds.to_vec())
+    }
+
+    /// Returns a page of the current user's first-degree connections.
+    pub async fn get_connections(
+        &self,
+        sort: ConnectionSort,
+        start: usize,
+        count: usize,
+    ) -> Result<Vec<OwnConnection>, LinkedinError> {
+        self.inner.get_connections(sort, start, count).await
+    }
+
+    /// Returns every first-degree connection of the current user.
+    pub async fn get_all_connections(
+        &self,
+        sort: ConnectionSort,
+    ) -> Result<Vec<OwnConnection>, LinkedinError> {
+        self.inner.get_all_connections(sort).await
+    }
+
     /// Returns a LinkedIn profile's contact information.
     pub async fn get_profile_contact_info(
         &self,
@@ -170,11 +265,51 @@ impl Linkedin {
         self.inner.remove_connection(public_id).await
     }
 
-    /// Return list of metadata of the user's conversations.
+    /// Follows a member, company, school or hashtag, e.g. the URN from
+    /// `FollowKind::Company.urn("1441")`.
+    pub async fn follow(&self, entity: &UniformResourceName) -> Result<(), LinkedinError> {
+        self.inner.follow(entity).await
+    }
+
+    /// Unfollows a member, company, school or hashtag.
+    pub async fn unfollow(&self, entity: &UniformResourceName) -> Result<(), LinkedinError> {
+        self.inner.unfollow(entity).await
+    }
+
+    /// Returns every entity of `kind` the current user follows.
+    pub async fn get_following(
+        &self,
+        kind: FollowKind,
+    ) -> Result<Vec<FollowedEntity>, LinkedinError> {
+        self.inner.get_following(kind).await
+    }
+
+    /// Returns whether the current user follows an entity.
+    pub async fn is_following(&self, entity: &UniformResourceName) -> Result<bool, LinkedinError> {
+        self.inner.is_following(entity).await
+    }
+
+    /// Return list of metadata of the user's most recent conversations.
     pub async fn get_conversations(&self) -> Result<Vec<Conversation>, LinkedinError> {
         self.inner.get_conversations().await
     }
 
+    /// Returns a reader over every conversation in the inbox, optionally filtered.
+    pub fn inbox(&self) -> Inbox {
+        Inbox::new(self.inner.clone())
+    }
+
+    /// Returns an exporter that writes every conversation to `directory`.
+    pub fn exporter(&self, directory: impl Into<PathBuf>, format: ExportFormat) -> Exporter {
+        Exporter::new(self.inner.clone(), directory.into(), format)
+    }
+
+    /// Subscribes to realtime messaging events, falling back to inbox polling
+    /// when the event stream is unavailable.
+    pub fn subscribe(&self) -> Subscription {
+        Subscription::new(self.inner.clone())
+    }
+
     /// Return conversation details for a profile URN ID.
     pub async fn get_conversation_details(
         &self,
@@ -183,36 +318,253 @@ impl Linkedin {
         self.inner.get_conversation_details(profile_urn_id).await
     }
 
-    /// Return a conversation.
+    /// Return a page of a conversation's messages, optionally only those created before a time.
     pub async fn get_conversation(
         &self,
         conversation_urn_id: &str,
-    ) -> Result<Conversation, LinkedinError> {
-        self.inner.get_conversation(conversation_urn_id).await
+        created_before: Option<OffsetDateTime>,
+    ) -> Result<Vec<Message>, LinkedinError> {
+        self.inner
+            .get_conversation(conversation_urn_id, created_before)
+            .await
+    }
+
+    /// Return a conversation's full history, oldest first, walking back page by page.
+    pub async fn get_conversation_history(
+        &self,
+        conversation_urn_id: &str,
+        max_results: Option<usize>,
+    ) -> Result<Vec<Message>, LinkedinError> {
+        self.inner
+            .get_conversation_history(conversation_urn_id, max_results)
+            .await
     }
 
-    /// Sends a message to a conversation or recipients.
+    /// Sends a message to a conversation or recipients. Recipients are profile
+    /// ids; full profile URNs are reduced to their id.
+    ///
+    /// Fails with `LinkedinError::InvalidInput` when the body is empty or neither
+    /// a conversation nor any recipients are given.
     pub async fn send_message(
         &self,
         conversation_urn_id: Option<&str>,
         recipients: Option<Vec<String>>,
         message_body: &str,
-    ) -> Result<bool, LinkedinError> {
+    ) -> Result<SentMessage, LinkedinError> {
         self.inner
             .send_message(conversation_urn_id, recipients, message_body)
             .await
     }
 
+    /// Sends a message with mentions, links, images or file attachments,
+    /// uploading any files first.
+    pub async fn send_rich_message(
+        &self,
+        conversation_urn_id: Option<&str>,
+        recipients: Option<Vec<String>>,
+        message: &NewMessage,
+    ) -> Result<SentMessage, LinkedinError> {
+        self.inner
+            .send_rich_message(conversation_urn_id, recipients, message)
+            .await
+    }
+
     /// Mark a conversation as seen.
     pub async fn mark_conversation_as_seen(
         &self,
         conversation_urn_id: &str,
-    ) -> Result<bool, LinkedinError> {
+    ) -> Result<ConversationUpdate, LinkedinError> {
         self.inner
             .mark_conversation_as_seen(conversation_urn_id)
             .await
     }
 
+    /// Show the other participants that the current user is typing.
+    pub async fn send_typing_indicator(
+        &self,
+        conversation_urn_id: &str,
+    ) -> Result<(), LinkedinError> {
+        self.inner.send_typing_indicator(conversation_urn_id).await
+    }
+
+    /// Send a read receipt for a single message event.
+    pub async fn mark_message_as_seen(
+        &self,
+        message_urn: &UniformResourceName,
+    ) -> Result<(), LinkedinError> {
+        self.inner.mark_message_as_seen(message_urn).await
+    }
+
+    /// Get how far each participant has read in a conversation.
+    pub async fn get_seen_receipts(
+        &self,
+        conversation_urn_id: &str,
+    ) -> Result<Vec<SeenReceipt>, LinkedinError> {
+        self.inner.get_seen_receipts(conversation_urn_id).await
+    }
+
+    /// Apply an action to a conversation.
+    pub async fn update_conversation(
+        &self,
+        conversation_urn_id: &str,
+        action: ConversationAction,
+    ) -> Result<ConversationUpdate, LinkedinError> {
+        self.inner
+            .update_conversation(conversation_urn_id, action)
+            .await
+    }
+
+    /// Mark a conversation as unread.
+    pub async fn mark_conversation_as_unread(
+        &self,
+        conversation_urn_id: &str,
+    ) -> Result<ConversationUpdate, LinkedinError> {
+        self.inner
+            .update_conversation(conversation_urn_id, ConversationAction::MarkUnread)
+            .await
+    }
+
+    /// Archive a conversation.
+    pub async fn archive_conversation(
+        &self,
+        conversation_urn_id: &str,
+    ) -> Result<ConversationUpdate, LinkedinError> {
+        self.inner
+            .update_conversation(conversation_urn_id, ConversationAction::Archive)
+            .await
+    }
+
+    /// Move an archived conversation back to the inbox.
+    pub async fn unarchive_conversation(
+        &self,
+        conversation_urn_id: &str,
+    ) -> Result<ConversationUpdate, LinkedinError> {
+        self.inner
+            .update_conversation(conversation_urn_id, ConversationAction::Unarchive)
+            .await
+    }
+
+    /// Mute notifications for a conversation.
+    pub async fn mute_conversation(
+        &self,
+        conversation_urn_id: &str,
+    ) -> Result<ConversationUpdate, LinkedinError> {
+        self.inner
+            .update_conversation(conversation_urn_id, ConversationAction::Mute)
+            .await
+    }
+
+    /// Unmute a conversation.
+    pub async fn unmute_conversation(
+        &self,
+        conversation_urn_id: &str,
+    ) -> Result<ConversationUpdate, LinkedinError> {
+        self.inner
+            .update_conversation(conversation_urn_id, ConversationAction::Unmute)
+            .await
+    }
+
+    /// Star a conversation.
+    pub async fn star_conversation(
+        &self,
+        conversation_urn_id: &str,
+    ) -> Result<ConversationUpdate, LinkedinError> {
+        self.inner
+            .update_conversation(conversation_urn_id, ConversationAction::Star)
+            .await
+    }
+
+    /// Remove the star from a conversation.
+    pub async fn unstar_conversation(
+        &self,
+        conversation_urn_id: &str,
+    ) -> Result<ConversationUpdate, LinkedinError> {
+        self.inner
+            .update_conversation(conversation_urn_id, ConversationAction::Unstar)
+            .await
+    }
+
+    /// Leave a group conversation.
+    pub async fn leave_conversation(
+        &self,
+        conversation_urn_id: &str,
+    ) -> Result<ConversationUpdate, LinkedinError> {
+        self.inner
+            .update_conversation(conversation_urn_id, ConversationAction::Leave)
+            .await
+    }
+
+    /// Delete a conversation.
+    pub async fn delete_conversation(
+        &self,
+        conversation_urn_id: &str,
+    ) -> Result<ConversationUpdate, LinkedinError> {
+        self.inner
+            .update_conversation(conversation_urn_id, ConversationAction::Delete)
+            .await
+    }
+
+    /// Start a named group conversation with an opening message.
+    pub async fn create_group_conversation(
+        &self,
+        name: &str,
+        participants: &[UniformResourceName],
+        message: &NewMessage,
+    ) -> Result<SentMessage, LinkedinError> {
+        self.inner
+            .create_group_conversation(name, participants, message)
+            .await
+    }
+
+    /// Add profiles to a group conversation.
+    pub async fn add_conversation_participants(
+        &self,
+        conversation_urn_id: &str,
+        participants: &[UniformResourceName],
+    ) -> Result<ConversationUpdate, LinkedinError> {
+        self.inner
+            .update_conversation(
+                conversation_urn_id,
+                ConversationAction::AddParticipants(participants.to_vec()),
+            )
+            .await
+    }
+
+    /// Remove profiles from a group conversation.
+    pub async fn remove_conversation_participants(
+        &self,
+        conversation_urn_id: &str,
+        participants: &[UniformResourceName],
+    ) -> Result<ConversationUpdate, LinkedinError> {
+        self.inner
+            .update_conversation(
+                conversation_urn_id,
+                ConversationAction::RemoveParticipants(participants.to_vec()),
+            )
+            .await
+    }
+
+    /// Get the current participants of a conversation.
+    pub async fn get_conversation_participants(
+        &self,
+        conversation_urn_id: &str,
+    ) -> Result<Vec<MiniProfile>, LinkedinError> {
+        self.inner
+            .get_conversation_participants(conversation_urn_id)
+            .await
+    }
+
+    /// Rename a group conversation.
+    pub async fn rename_conversation(
+        &self,
+        conversation_urn_id: &str,
+        name: &str,
+    ) -> Result<ConversationUpdate, LinkedinError> {
+        self.inner
+            .update_conversation(conversation_urn_id, ConversationAction::Rename(name.into()))
+            .await
+    }
+
     /// Get view statistics for the current profile.
     pub async fn get_current_profile_views(&self) -> Result<u64, LinkedinError> {
         self.inner.get_current_profile_views().await
@@ -228,6 +580,27 @@ impl Linkedin {
         self.inner.get_company(public_id).await
     }
 
+    /// Returns a job posting, including the skills it asks for.
+    pub async fn get_job(&self, job_id: &str) -> Result<JobPosting, LinkedinError> {
+        self.inner.get_job(job_id).await
+    }
+
+    /// Returns how the current user's skills match a job posting's requirements.
+    pub async fn get_job_skill_match(
+        &self,
+        job_id: &str,
+    ) -> Result<Vec<JobSkillMatch>, LinkedinError> {
+        self.inner.get_job_skill_match(job_id).await
+    }
+
+    /// Returns applicant insights for a job posting (requires premium).
+    pub async fn get_job_applicant_insights(
+        &self,
+        job_id: &str,
+    ) -> Result<JobApplicantInsights, LinkedinError> {
+        self.inner.get_job_applicant_insights(job_id).await
+    }
+
     /// Perform a LinkedIn search.
     pub async fn search(
         &self,
@@ -251,7 +624,7 @@ impl Linkedin {
         public_id: Option<&str>,
         urn_id: Option<&str>,
         max_results: Option<usize>,
-    ) -> Result<Vec<Value>, LinkedinError> {
+    ) -> Result<Vec<FeedUpdate>, LinkedinError> {
         self.inner
             .get_company_updates(public_id, urn_id, max_results)
             .await
@@ -263,12 +636,78 @@ impl Linkedin {
         public_id: Option<&str>,
         urn_id: Option<&str>,
         max_results: Option<usize>,
-    ) -> Result<Vec<Value>, LinkedinError> {
+    ) -> Result<Vec<FeedUpdate>, LinkedinError> {
         self.inner
             .get_profile_updates(public_id, urn_id, max_results)
             .await
     }
 
+    /// Publish a post, uploading any attached media first. Returns the new share URN.
+    pub async fn create_post(&self, post: &NewPost) -> Result<UniformResourceName, LinkedinError> {
+        self.inner.create_post(post).await
+    }
+
+    /// Returns a reader over the current user's home feed.
+    pub fn home_feed(&self) -> HomeFeed {
+        HomeFeed::new(self.inner.clone())
+    }
+
+    /// Returns a page of comments on a post, with replies where LinkedIn inlines them.
+    pub async fn get_comments(
+        &self,
+        post: &UniformResourceName,
+        start: usize,
+        count: usize,
+    ) -> Result<Vec<Comment>, LinkedinError> {
+        self.inner.get_comments(post, start, count).await
+    }
+
+    /// Comment on a post, or reply to a comment. Returns the new comment URN.
+    pub async fn add_comment(
+        &self,
+        post: &UniformResourceName,
+        parent_comment: Option<&UniformResourceName>,
+        text: &str,
+    ) -> Result<UniformResourceName, LinkedinError> {
+        self.inner.add_comment(post, parent_comment, text).await
+    }
+
+    /// Delete one of the current user's comments.
+    pub async fn delete_comment(&self, comment: &UniformResourceName) -> Result<(), LinkedinError> {
+        self.inner.delete_comment(comment).await
+    }
+
+    /// Returns a page of reactions on a post, optionally of a single type.
+    pub async fn get_reactions(
+        &self,
+        post: &UniformResourceName,
+        reaction_type: Option<ReactionType>,
+        start: usize,
+        count: usize,
+    ) -> Result<Vec<Reaction>, LinkedinError> {
+        self.inner
+            .get_reactions(post, reaction_type, start, count)
+            .await
+    }
+
+    /// React to a post.
+    pub async fn react(
+        &self,
+        post: &UniformResourceName,
+        reaction_type: ReactionType,
+    ) -> Result<(), LinkedinError> {
+        self.inner.react(post, reaction_type).await
+    }
+
+    /// Remove a reaction from a post.
+    pub async fn unreact(
+        &self,
+        post: &UniformResourceName,
+        reaction_type: ReactionType,
+    ) -> Result<(), LinkedinError> {
+        self.inner.unreact(post, reaction_type).await
+    }
+
     /// Get all invitations for the current profile.
     pub async fn get_invitations(
         &self,
@@ -278,18 +717,75 @@ impl Linkedin {
         self.inner.get_invitations(start, limit).await
     }
 
+    /// Send a connection invitation, with an optional note of up to 300 characters.
+    pub async fn send_invitation(
+        &self,
+        public_id: &str,
+        note: Option<&str>,
+    ) -> Result<InvitationResult, LinkedinError> {
+        self.inner
+            .send_invitation(Some(public_id), None, note)
+            .await
+    }
+
+    /// Send a connection invitation by profile URN.
+    pub async fn send_invitation_by_urn(
+        &self,
+        urn: &UniformResourceName,
+        note: Option<&str>,
+    ) -> Result<InvitationResult, LinkedinError> {
+        self.inner.send_invitation(None, Some(urn), note).await
+    }
+
+    /// Get a page of connection invitations sent by the current profile.
+    pub async fn get_sent_invitations(
+        &self,
+        start: usize,
+        limit: usize,
+    ) -> Result<Vec<SentInvitation>, LinkedinError> {
+        self.inner.get_sent_invitations(start, limit).await
+    }
+
+    /// Get every pending connection invitation sent by the current profile.
+    pub async fn get_all_sent_invitations(&self) -> Result<Vec<SentInvitation>, LinkedinError> {
+        self.inner.get_all_sent_invitations().await
+    }
+
+    /// Withdraw a sent invitation.
+    pub async fn withdraw_invitation(
+        &self,
+        invitation: &SentInvitation,
+    ) -> Result<(), LinkedinError> {
+        self.inner
+            .reply_invitation(
+                &invitation.entity_urn,
+                &invitation.shared_secret,
+                InvitationAction::Withdraw,
+            )
+            .await
+    }
+
     /// Reply to an invitation.
     pub async fn reply_invitation(
         &self,
-        invitation_entity_urn: &str,
-        invitation_shared_secret: &str,
-        action: &str,
-    ) -> Result<bool, LinkedinError> {
+        invitation_urn: &UniformResourceName,
+        shared_secret: &str,
+        action: InvitationAction,
+    ) -> Result<(), LinkedinError> {
         self.inner
-            .reply_invitation(invitation_entity_urn, invitation_shared_secret, action)
+            .reply_invitation(invitation_urn, shared_secret, action)
             .await
     }
 
+    /// Accept or ignore every received invitation according to `rules`,
+    /// returning what was decided for each.
+    pub async fn triage_invitations(
+        &self,
+        rules: &TriageRules,
+    ) -> Result<TriageReport, LinkedinError> {
+        rules.apply(&self.inner).await
+    }
+
     /// Get current user profile.
     pub async fn get_user_profile(&self) -> Result<Value, LinkedinError> {
         self.inner.get_user_profile().await
@@ -1,25 +1,37 @@
 use serde_json::{json, Value};
-use std::collections::HashMap;
-use std::fs::write;
-use url::Url;
+use std::collections::{HashMap, HashSet};
+use time::{Duration, OffsetDateTime};
 use urlencoding::encode;
 
 use crate::client::Client;
 use crate::error::LinkedinError;
-use crate::types::ProfileView;
+use crate::inbox::InboxFilter;
+use crate::types::{
+    event_conversation_urn, Comment, ConnectionSort, ConversationAction, ConversationUpdate,
+    FeedUpdate, FollowKind, FollowedEntity, InsightBucket, InvitationAction, InvitationResult,
+    JobApplicantInsights, JobPosting, JobSkillMatch, MediaFile, MediaUploadType, Message,
+    MiniProfile, NewMessage, NewPost, OwnConnection, PostMedia, PostVisibility, ProfileView,
+    Reaction, ReactionType, SeenReceipt, SentInvitation, SentMessage, SharedConnections, SpanKind,
+    MAX_INVITATION_NOTE_LENGTH,
+};
+use crate::utils::{datetime_from_millis, tracking_id};
 use crate::{
-    types::Education, types::Experience, Company, Connection, ContactInfo, Conversation,
-    ConversationDetails, Identity, Invitation, MemberBadges, NetworkInfo, PersonSearchResult,
-    Profile, School, SearchPeopleParams, Skill, UniformResourceName,
+    Company, Connection, ContactInfo, Conversation, ConversationDetails, Identity, Invitation,
+    MemberBadges, NetworkInfo, PersonSearchResult, School, SearchPeopleParams, Skill,
+    UniformResourceName,
 };
 
 const MAX_UPDATE_COUNT: usize = 100;
 const MAX_SEARCH_COUNT: usize = 49;
 const MAX_REPEATED_REQUESTS: usize = 200;
+const MAX_INVITATION_COUNT: usize = 100;
+const MAX_CONNECTION_COUNT: usize = 40;
+const MAX_CONNECTION_PAGES: usize = 750;
+const MAX_FOLLOWING_COUNT: usize = 100;
 
 #[derive(Clone)]
 pub struct LinkedinInner {
-    client: Client,
+    pub(crate) client: Client,
 }
 
 impl LinkedinInner {
@@ -49,10 +61,7 @@ impl LinkedinInner {
             .get(&format!("/identity/profiles/{id}/profileView"))
             .await?;
         if res.status() != 200 {
-            return Err(LinkedinError::RequestFailed(format!(
-                "status {}",
-                res.status()
-            )));
+            return Err(LinkedinError::from_status(res.status()));
         }
 
         let data: serde_json::Value = res.json().await?;
@@ -233,69 +242,133 @@ impl LinkedinInner {
 
         let results = self.search_people(params).await?;
 
-        Ok(results
-            .into_iter()
-            .map(|r| Connection {
-                urn_id: r.urn_id,
-                public_id: r.public_id,
-                distance: r.distance,
-            })
-            .collect())
+        Ok(results.into_iter().map(Connection::from).collect())
     }
 
-    pub async fn search(
+    /// One search request for a profile's first-degree connections, with the
+    /// number of raw results so callers can page past unparseable ones.
+    pub(crate) async fn get_profile_connections_page(
         &self,
-        mut params: HashMap<String, String>,
-        limit: Option<usize>,
-    ) -> Result<Vec<Value>, LinkedinError> {
-        let count = limit.unwrap_or(MAX_SEARCH_COUNT).min(MAX_SEARCH_COUNT);
+        uniform_resource_name: &str,
+        start: usize,
+        count: usize,
+    ) -> Result<(Vec<Connection>, usize), LinkedinError> {
+        let params = people_search_params(&SearchPeopleParams {
+            connection_of: Some(uniform_resource_name.to_string()),
+            network_depth: Some("F".to_string()),
+            ..Default::default()
+        });
+        let params = with_search_defaults(params, count.min(MAX_SEARCH_COUNT));
+
+        let elements = self.search_page(&params, start).await?;
+        let connections = elements
+            .iter()
+            .filter_map(person_from_search)
+            .map(Connection::from)
+            .collect();
+
+        Ok((connections, elements.len()))
+    }
+
+    pub async fn get_shared_connections(
+        &self,
+        public_id: Option<&str>,
+        urn: Option<&UniformResourceName>,
+    ) -> Result<SharedConnections, LinkedinError> {
+        let urn = match (urn, public_id) {
+            (Some(urn), _) => urn.clone(),
+            (None, Some(public_id)) => self.get_profile_urn(public_id).await?,
+            (None, None) => {
+                return Err(LinkedinError::InvalidInput(
+                    "public_id or uniform_resource_name required".into(),
+                ))
+            }
+        };
 
-        let default_params = vec![
-            ("count".to_string(), count.to_string()),
-            ("filters".to_string(), "List()".to_string()),
-            ("origin".to_string(), "GLOBAL_SEARCH_HEADER".to_string()),
-            ("q".to_string(), "all".to_string()),
-            ("start".to_string(), "0".to_string()),
-            ("queryContext".to_string(), "List(spellCorrectionEnabled->true,relatedSearchesEnabled->true,kcardTypes->PROFILE|COMPANY)".to_string()),
-        ];
+        // Their connections that are also first-degree connections of ours
+        let connections = self.get_profile_connections(&urn.id).await?;
 
-        for (key, value) in default_params {
-            params.entry(key).or_insert(value);
+        let res = self
+            .client
+            .get(&format!("/identity/profiles/{}/highlights", urn.id))
+            .await?;
+        if res.status() != 200 {
+            return Err(LinkedinError::from_status(res.status()));
         }
+        let data: Value = res.json().await?;
 
-        let mut results = vec![];
-        let mut start = 0;
-        let target_limit = limit.unwrap_or(usize::MAX);
+        Ok(SharedConnections::from_highlights(&data, connections))
+    }
 
-        loop {
-            params.insert("start".to_string(), start.to_string());
+    pub async fn get_connections(
+        &self,
+        sort: ConnectionSort,
+        start: usize,
+        count: usize,
+    ) -> Result<Vec<OwnConnection>, LinkedinError> {
+        Ok(self.get_connections_page(sort, start, count).await?.0)
+    }
 
-            let query_string: String = params
-                .iter()
-                .map(|(k, v)| format!("{}={}", encode(k), encode(v)))
-                .collect::<Vec<_>>()
-                .join("&");
+    /// A page of connections, with the number of raw elements returned so
+    /// callers can page past elements that failed to parse.
+    async fn get_connections_page(
+        &self,
+        sort: ConnectionSort,
+        start: usize,
+        count: usize,
+    ) -> Result<(Vec<OwnConnection>, usize), LinkedinError> {
+        let res = self
+            .client
+            .get(&format!(
+                "/relationships/dash/connections?decorationId=com.linkedin.voyager.dash.deco.web.mynetwork.ConnectionListWithProfile-16&count={count}&q=search&sortType={}&start={start}",
+                sort.as_str()
+            ))
+            .await?;
+        if res.status() != 200 {
+            return Err(LinkedinError::from_status(res.status()));
+        }
 
-            let res = self
-                .client
-                .get(&format!("/search/blended?{query_string}"))
-                .await?;
-            let data: Value = res.json().await?;
+        let data: Value = res.json().await?;
 
-            let mut new_elements = vec![];
+        Ok(parse_elements(&data, OwnConnection::from_voyager))
+    }
 
-            if let Some(elements) = data
-                .get("data")
-                .and_then(|d| d.get("elements"))
-                .and_then(|e| e.as_array())
-            {
-                for element in elements {
-                    if let Some(inner_elements) = element.get("elements").and_then(|e| e.as_array())
-                    {
-                        new_elements.extend(inner_elements.iter().cloned());
-                    }
-                }
+    pub async fn get_all_connections(
+        &self,
+        sort: ConnectionSort,
+    ) -> Result<Vec<OwnConnection>, LinkedinError> {
+        let mut connections = vec![];
+        let mut start = 0;
+
+        // LinkedIn caps a member at 30,000 connections
+        for _ in 0..MAX_CONNECTION_PAGES {
+            let (page, returned) = self
+                .get_connections_page(sort, start, MAX_CONNECTION_COUNT)
+                .await?;
+            connections.extend(page);
+            start += returned;
+            if returned < MAX_CONNECTION_COUNT {
+                break;
             }
+        }
+
+        Ok(connections)
+    }
+
+    pub async fn search(
+        &self,
+        params: HashMap<String, String>,
+        limit: Option<usize>,
+    ) -> Result<Vec<Value>, LinkedinError> {
+        let count = limit.unwrap_or(MAX_SEARCH_COUNT).min(MAX_SEARCH_COUNT);
+        let params = with_search_defaults(params, count);
+
+        let mut results = vec![];
+        let mut start = 0;
+        let target_limit = limit.unwrap_or(usize::MAX);
+
+        loop {
+            let new_elements = self.search_page(&params, start).await?;
 
             if new_elements.is_empty() {
                 break;
@@ -318,79 +391,53 @@ impl LinkedinInner {
         Ok(results.into_iter().take(target_limit).collect())
     }
 
-    pub async fn search_people(
+    /// One request to blended search, flattened to its result elements.
+    async fn search_page(
         &self,
-        params: SearchPeopleParams,
-    ) -> Result<Vec<PersonSearchResult>, LinkedinError> {
-        let mut filters = vec!["resultType->PEOPLE".to_string()];
-
-        if let Some(connection_of) = &params.connection_of {
-            filters.push(format!("connectionOf->{connection_of}"));
-        }
-        if let Some(network_depth) = &params.network_depth {
-            filters.push(format!("network->{network_depth}"));
-        }
-        if let Some(regions) = &params.regions {
-            filters.push(format!("geoRegion->{}", regions.join("|")));
-        }
-        if let Some(industries) = &params.industries {
-            filters.push(format!("industry->{}", industries.join("|")));
-        }
-        if let Some(current_company) = &params.current_company {
-            filters.push(format!("currentCompany->{}", current_company.join("|")));
-        }
-        if let Some(past_companies) = &params.past_companies {
-            filters.push(format!("pastCompany->{}", past_companies.join("|")));
-        }
-        if let Some(profile_languages) = &params.profile_languages {
-            filters.push(format!("profileLanguage->{}", profile_languages.join("|")));
-        }
-        if let Some(nonprofit_interests) = &params.nonprofit_interests {
-            filters.push(format!(
-                "nonprofitInterest->{}",
-                nonprofit_interests.join("|")
-            ));
-        }
-        if let Some(schools) = &params.schools {
-            filters.push(format!("schools->{}", schools.join("|")));
-        }
+        params: &HashMap<String, String>,
+        start: usize,
+    ) -> Result<Vec<Value>, LinkedinError> {
+        let mut params = params.clone();
+        params.insert("start".to_string(), start.to_string());
 
-        let mut search_params = HashMap::new();
-        search_params.insert(
-            "filters".to_string(),
-            format!("List({})", filters.join(",")),
-        );
+        let query_string: String = params
+            .iter()
+            .map(|(k, v)| format!("{}={}", encode(k), encode(v)))
+            .collect::<Vec<_>>()
+            .join("&");
 
-        if let Some(keywords) = &params.keywords {
-            search_params.insert("keywords".to_string(), keywords.clone());
-        }
+        let res = self
+            .client
+            .get(&format!("/search/blended?{query_string}"))
+            .await?;
+        let data: Value = res.json().await?;
 
-        let data = self.search(search_params, params.limit).await?;
+        let mut elements = vec![];
 
-        let mut results = vec![];
-        for item in data {
-            if let Some(public_id) = item.get("publicIdentifier").and_then(|p| p.as_str()) {
-                let urn_id = item
-                    .get("targetUrn")
-                    .and_then(|u| u.as_str())
-                    .and_then(|s| UniformResourceName::parse(s).ok())
-                    .map(|urn| urn.id)
-                    .unwrap_or_default();
-                let distance = item
-                    .get("memberDistance")
-                    .and_then(|d| d.get("value"))
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("");
-
-                results.push(PersonSearchResult {
-                    urn_id: urn_id.to_string(),
-                    public_id: public_id.to_string(),
-                    distance: distance.to_string(),
-                });
+        if let Some(clusters) = data
+            .get("data")
+            .and_then(|d| d.get("elements"))
+            .and_then(|e| e.as_array())
+        {
+            for cluster in clusters {
+                if let Some(inner_elements) = cluster.get("elements").and_then(|e| e.as_array()) {
+                    elements.extend(inner_elements.iter().cloned());
+                }
             }
         }
 
-        Ok(results)
+        Ok(elements)
+    }
+
+    pub async fn search_people(
+        &self,
+        params: SearchPeopleParams,
+    ) -> Result<Vec<PersonSearchResult>, LinkedinError> {
+        let data = self
+            .search(people_search_params(&params), params.limit)
+            .await?;
+
+        Ok(data.iter().filter_map(person_from_search).collect())
     }
 
     pub async fn get_company_updates(
@@ -398,7 +445,7 @@ impl LinkedinInner {
         public_id: Option<&str>,
         uniform_resource_name: Option<&str>,
         max_results: Option<usize>,
-    ) -> Result<Vec<Value>, LinkedinError> {
+    ) -> Result<Vec<FeedUpdate>, LinkedinError> {
         let id = public_id.or(uniform_resource_name).ok_or_else(|| {
             LinkedinError::InvalidInput(
                 "Either public_id or uniform_resource_name must be provided".to_string(),
@@ -435,7 +482,7 @@ impl LinkedinInner {
             }
         }
 
-        Ok(results)
+        Ok(results.iter().map(FeedUpdate::from_update_v2).collect())
     }
 
     pub async fn get_profile_updates(
@@ -443,7 +490,7 @@ impl LinkedinInner {
         public_id: Option<&str>,
         uniform_resource_name: Option<&str>,
         max_results: Option<usize>,
-    ) -> Result<Vec<Value>, LinkedinError> {
+    ) -> Result<Vec<FeedUpdate>, LinkedinError> {
         let id = public_id.or(uniform_resource_name).ok_or_else(|| {
             LinkedinError::InvalidInput(
                 "Either public_id or uniform_resource_name must be provided".to_string(),
@@ -482,236 +529,1150 @@ impl LinkedinInner {
             }
         }
 
-        Ok(results)
-    }
-
-    pub async fn get_current_profile_views(&self) -> Result<u64, LinkedinError> {
-        let res = self.client.get("/identity/wvmpCards").await?;
-        let data: Value = res.json().await?;
-
-        let views = data
-            .get("elements")
-            .and_then(|e| e.get(0))
-            .and_then(|e| e.get("value"))
-            .and_then(|v| v.get("com.linkedin.voyager.identity.me.wvmpOverview.WvmpViewersCard"))
-            .and_then(|c| c.get("insightCards"))
-            .and_then(|i| i.get(0))
-            .and_then(|i| i.get("value"))
-            .and_then(|v| {
-                v.get("com.linkedin.voyager.identity.me.wvmpOverview.WvmpSummaryInsightCard")
-            })
-            .and_then(|s| s.get("numViews"))
-            .and_then(|n| n.as_u64())
-            .unwrap_or(0);
-
-        Ok(views)
+        Ok(results.iter().map(FeedUpdate::from_update_v2).collect())
     }
 
-    pub async fn get_school(&self, public_id: &str) -> Result<School, LinkedinError> {
-        let params = format!("?decorationId=com.linkedin.voyager.deco.organization.web.WebFullCompanyMain-12&q=universalName&universalName={public_id}");
+    pub async fn upload_media(
+        &self,
+        upload_type: MediaUploadType,
+        file: &MediaFile,
+    ) -> Result<UniformResourceName, LinkedinError> {
+        let payload = json!({
+            "mediaUploadType": upload_type.as_str(),
+            "fileSize": file.bytes.len(),
+            "filename": file.filename,
+        });
 
         let res = self
             .client
-            .get(&format!("/organization/companies{params}"))
+            .post(
+                "/voyagerVideoDashMediaUploadMetadata?action=upload",
+                &payload,
+            )
             .await?;
-        let data: Value = res.json().await?;
-
-        if let Some(status) = data.get("status") {
-            if status != 200 {
-                return Err(LinkedinError::RequestFailed(
-                    "School request failed".to_string(),
-                ));
-            }
+        if res.status() != 200 {
+            return Err(LinkedinError::from_status(res.status()));
         }
 
-        let school_data = data
-            .get("elements")
-            .and_then(|e| e.get(0))
-            .ok_or_else(|| LinkedinError::RequestFailed("No school data found".to_string()))?;
+        let data: Value = res.json().await?;
+        let value = data
+            .get("data")
+            .and_then(|d| d.get("value"))
+            .or_else(|| data.get("value"))
+            .ok_or_else(|| LinkedinError::RequestFailed("No upload metadata".into()))?;
 
-        let name = school_data
-            .get("name")
-            .and_then(|n| n.as_str())
-            .ok_or_else(|| LinkedinError::RequestFailed("No school name found".to_string()))?;
+        let urn = value
+            .get("urn")
+            .and_then(|u| u.as_str())
+            .ok_or_else(|| LinkedinError::RequestFailed("No media urn".into()))?;
+        let urn = UniformResourceName::parse(urn)?;
 
-        Ok(School {
-            name: name.to_string(),
-        })
-    }
+        let upload_url = value
+            .get("singleUploadUrl")
+            .and_then(|u| u.as_str())
+            .ok_or_else(|| LinkedinError::RequestFailed("No upload url".into()))?;
 
-    pub async fn get_company(&self, public_id: &str) -> Result<Company, LinkedinError> {
-        let params = format!("?decorationId=com.linkedin.voyager.deco.organization.web.WebFullCompanyMain-12&q=universalName&universalName={public_id}");
+        let upload_headers: Vec<(String, String)> = value
+            .get("singleUploadHeaders")
+            .and_then(|h| h.as_object())
+            .map(|headers| {
+                headers
+                    .iter()
+                    .filter_map(|(name, value)| Some((name.clone(), value.as_str()?.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default();
 
         let res = self
             .client
-            .get(&format!("/organization/companies{params}"))
+            .upload(upload_url, &upload_headers, file.bytes.clone())
             .await?;
-        let data: Value = res.json().await?;
-
-        if let Some(status) = data.get("status") {
-            if status != 200 {
-                return Err(LinkedinError::RequestFailed(
-                    data.get("message")
-                        .unwrap_or(&Value::String("Unknown error".to_string()))
-                        .as_str()
-                        .unwrap()
-                        .to_string(),
-                ));
-            }
+        if !res.status().is_success() {
+            return Err(LinkedinError::from_status(res.status()));
         }
 
-        let company_data = data
-            .get("elements")
-            .and_then(|e| e.get(0))
-            .ok_or_else(|| LinkedinError::RequestFailed("No company data found".to_string()))?;
+        Ok(urn)
+    }
 
-        let name = company_data
-            .get("name")
-            .and_then(|n| n.as_str())
-            .ok_or_else(|| LinkedinError::RequestFailed("No company name found".to_string()))?;
+    pub async fn create_post(&self, post: &NewPost) -> Result<UniformResourceName, LinkedinError> {
+        let media = match &post.media {
+            Some(PostMedia::Image { file, alt_text }) => {
+                let urn = self
+                    .upload_media(MediaUploadType::ImageSharing, file)
+                    .await?;
+                vec![json!({
+                    "category": "IMAGE",
+                    "mediaUrn": urn.as_str(),
+                    "tapTargets": [],
+                    "altText": alt_text.as_deref().unwrap_or_default(),
+                })]
+            }
+            Some(PostMedia::Document { file, title }) => {
+                let urn = self
+                    .upload_media(MediaUploadType::DocumentSharing, file)
+                    .await?;
+                vec![json!({
+                    "category": "NATIVE_DOCUMENT",
+                    "mediaUrn": urn.as_str(),
+                    "title": title,
+                })]
+            }
+            None => post
+                .link
+                .iter()
+                .map(|url| {
+                    json!({
+                        "category": "ARTICLE",
+                        "originalUrl": url.as_str(),
+                        "tapTargets": [],
+                    })
+                })
+                .collect(),
+        };
 
-        Ok(Company {
-            name: name.to_string(),
-        })
-    }
+        let attributes: Vec<Value> = post
+            .commentary
+            .spans
+            .iter()
+            .filter_map(|span| {
+                let kind = match &span.kind {
+                    SpanKind::ProfileMention { urn: Some(urn), .. }
+                    | SpanKind::CompanyMention { urn: Some(urn) } => {
+                        json!({ "entity": { "urn": urn.as_str() } })
+                    }
+                    SpanKind::Hashtag { tag } => {
+                        json!({ "hashtag": { "hashtagUrn": format!("urn:li:fsd_hashtag:{tag}") } })
+                    }
+                    _ => return None,
+                };
+                Some(json!({
+                    "start": span.start,
+                    "length": span.length,
+                    "attributeKindUnion": kind,
+                }))
+            })
+            .collect();
 
-    pub async fn get_conversation_details(
-        &self,
-        profile_uniform_resource_name: &str,
-    ) -> Result<ConversationDetails, LinkedinError> {
-        let res = self.client.get(&format!("/messaging/conversations?keyVersion=LEGACY_INBOX&q=participants&recipients=List({profile_uniform_resource_name})")).await?;
-        let data: Value = res.json().await?;
+        let payload = json!({
+            "visibleToConnectionsOnly": post.visibility == PostVisibility::Connections,
+            "externalAudienceProviders": [],
+            "commentaryV2": {
+                "text": post.commentary.text,
+                "attributes": attributes,
+            },
+            "origin": "FEED",
+            "allowedCommentersScope": "ALL",
+            "postState": "PUBLISHED",
+            "media": media,
+        });
 
-        let item = data
-            .get("elements")
-            .and_then(|e| e.get(0))
-            .ok_or_else(|| LinkedinError::RequestFailed("No conversation found".to_string()))?;
+        let res = self
+            .client
+            .post("/contentcreation/normShares", &payload)
+            .await?;
+        if !res.status().is_success() {
+            return Err(LinkedinError::from_status(res.status()));
+        }
 
-        let entity_urn = item
-            .get("entityUrn")
+        let restli_id = res
+            .headers()
+            .get("x-restli-id")
+            .and_then(|h| h.to_str().ok())
+            .map(|s| s.to_string());
+        let body = res.text().await?;
+        let data: Value = serde_json::from_str(&body).unwrap_or(Value::Null);
+
+        let urn = data
+            .pointer("/data/status/urn")
+            .or_else(|| data.pointer("/value/urn"))
+            .or_else(|| data.pointer("/urn"))
             .and_then(|u| u.as_str())
-            .ok_or(LinkedinError::RequestFailed("No entityUrn".into()))?;
-        let urn = UniformResourceName::parse(entity_urn)?;
-        let id = urn.id;
+            .map(|s| s.to_string())
+            .or(restli_id)
+            .ok_or_else(|| LinkedinError::RequestFailed("No share urn in response".into()))?;
 
-        Ok(ConversationDetails { id: id.to_string() })
+        UniformResourceName::parse(&urn)
     }
 
-    pub async fn get_conversations(&self) -> Result<Vec<Conversation>, LinkedinError> {
+    pub async fn get_comments(
+        &self,
+        post: &UniformResourceName,
+        start: usize,
+        count: usize,
+    ) -> Result<Vec<Comment>, LinkedinError> {
+        let update_id = format!("{}:{}", post.namespace, post.id);
         let res = self
             .client
-            .get("/messaging/conversations?keyVersion=LEGACY_INBOX")
+            .get(&format!(
+                "/feed/comments?count={count}&start={start}&q=comments&sortOrder=RELEVANCE&updateId={}",
+                encode(&update_id)
+            ))
             .await?;
+        if res.status() != 200 {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
         let data: Value = res.json().await?;
 
-        let mut conversations = vec![];
+        Ok(data
+            .get("elements")
+            .and_then(|e| e.as_array())
+            .map(|elements| elements.iter().map(Comment::from_voyager).collect())
+            .unwrap_or_default())
+    }
 
-        if let Some(elements) = data.get("elements").and_then(|e| e.as_array()) {
-            for element in elements {
-                if let Some(entity_urn) = element.get("entityUrn").and_then(|u| u.as_str()) {
-                    let id = UniformResourceName::parse(entity_urn).unwrap().id;
-                    conversations.push(Conversation { id });
-                }
+    pub async fn add_comment(
+        &self,
+        post: &UniformResourceName,
+        parent_comment: Option<&UniformResourceName>,
+        text: &str,
+    ) -> Result<UniformResourceName, LinkedinError> {
+        if text.is_empty() {
+            return Err(LinkedinError::InvalidInput("Comment text is empty".into()));
+        }
+
+        let mut payload = json!({
+            "threadUrn": post.as_str(),
+            "commentV2": {
+                "text": text,
+                "attributes": []
             }
+        });
+        if let Some(parent) = parent_comment {
+            payload["parentCommentUrn"] = json!(parent.as_str());
         }
 
-        Ok(conversations)
+        let res = self.client.post("/feed/comments", &payload).await?;
+        if !res.status().is_success() {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        let restli_id = res
+            .headers()
+            .get("x-restli-id")
+            .and_then(|h| h.to_str().ok())
+            .map(|s| s.to_string());
+        let body = res.text().await?;
+        let data: Value = serde_json::from_str(&body).unwrap_or(Value::Null);
+
+        let urn = data
+            .pointer("/value/urn")
+            .or_else(|| data.pointer("/data/urn"))
+            .and_then(|u| u.as_str())
+            .map(|s| s.to_string())
+            .or(restli_id)
+            .ok_or_else(|| LinkedinError::RequestFailed("No comment urn in response".into()))?;
+
+        UniformResourceName::parse(&urn)
     }
 
-    pub async fn get_conversation(
-        &self,
-        conversation_uniform_resource_name: &str,
-    ) -> Result<Conversation, LinkedinError> {
+    pub async fn delete_comment(&self, comment: &UniformResourceName) -> Result<(), LinkedinError> {
         let res = self
             .client
-            .get(&format!(
-                "/messaging/conversations/{conversation_uniform_resource_name}/events"
-            ))
+            .delete(&format!("/feed/comments/{}", encode(&comment.as_str())))
             .await?;
-        let _data: Value = res.json().await?;
+        if !res.status().is_success() {
+            return Err(LinkedinError::from_status(res.status()));
+        }
 
-        Ok(Conversation {
-            id: conversation_uniform_resource_name.to_string(),
-        })
+        Ok(())
     }
 
-    pub async fn send_message(
+    pub async fn get_reactions(
+        &self,
+        post: &UniformResourceName,
+        reaction_type: Option<ReactionType>,
+        start: usize,
+        count: usize,
+    ) -> Result<Vec<Reaction>, LinkedinError> {
+        let mut url = format!(
+            "/voyagerSocialDashReactions?decorationId=com.linkedin.voyager.dash.deco.social.ReactionsByTypeWithProfileActions-13&count={count}&q=reactionType&start={start}&threadUrn={}",
+            encode(&post.as_str())
+        );
+        if let Some(reaction_type) = reaction_type {
+            url.push_str(&format!("&reactionType={}", reaction_type.as_str()));
+        }
+
+        let res = self.client.get(&url).await?;
+        if res.status() != 200 {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        let data: Value = res.json().await?;
+
+        let mut reactions = vec![];
+
+        if let Some(elements) = data.get("elements").and_then(|e| e.as_array()) {
+            for element in elements {
+                let Some(reaction_type) = element
+                    .get("reactionType")
+                    .and_then(|t| t.as_str())
+                    .and_then(|t| t.parse().ok())
+                else {
+                    continue;
+                };
+                let lockup = element.get("reactorLockup");
+
+                reactions.push(Reaction {
+                    reaction_type,
+                    actor_urn: element
+                        .get("actorUrn")
+                        .and_then(|u| u.as_str())
+                        .and_then(|u| UniformResourceName::parse(u).ok()),
+                    name: lockup
+                        .and_then(|l| l.get("title"))
+                        .and_then(|t| t.get("text"))
+                        .and_then(|t| t.as_str())
+                        .map(|s| s.to_string()),
+                    headline: lockup
+                        .and_then(|l| l.get("subtitle"))
+                        .and_then(|t| t.get("text"))
+                        .and_then(|t| t.as_str())
+                        .map(|s| s.to_string()),
+                });
+            }
+        }
+
+        Ok(reactions)
+    }
+
+    pub async fn react(
+        &self,
+        post: &UniformResourceName,
+        reaction_type: ReactionType,
+    ) -> Result<(), LinkedinError> {
+        let res = self
+            .client
+            .post(
+                &format!(
+                    "/voyagerSocialDashReactions?threadUrn={}",
+                    encode(&post.as_str())
+                ),
+                &json!({ "reactionType": reaction_type.as_str() }),
+            )
+            .await?;
+        if !res.status().is_success() {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        Ok(())
+    }
+
+    pub async fn unreact(
+        &self,
+        post: &UniformResourceName,
+        reaction_type: ReactionType,
+    ) -> Result<(), LinkedinError> {
+        let res = self
+            .client
+            .delete(&format!(
+                "/voyagerSocialDashReactions?threadUrn={}&reactionType={}",
+                encode(&post.as_str()),
+                reaction_type.as_str()
+            ))
+            .await?;
+        if !res.status().is_success() {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) async fn get_feed_page(
+        &self,
+        start: usize,
+        count: usize,
+        pagination_token: Option<&str>,
+    ) -> Result<(Vec<FeedUpdate>, Option<String>), LinkedinError> {
+        let mut url = format!("/feed/updatesV2?count={count}&q=chronFeed&start={start}");
+        if let Some(token) = pagination_token {
+            url.push_str(&format!("&paginationToken={}", encode(token)));
+        }
+
+        let res = self.client.get(&url).await?;
+        if res.status() != 200 {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        let data: Value = res.json().await?;
+
+        let updates = data
+            .get("elements")
+            .and_then(|e| e.as_array())
+            .map(|elements| elements.iter().map(FeedUpdate::from_update_v2).collect())
+            .unwrap_or_default();
+        let pagination_token = data
+            .get("metadata")
+            .and_then(|m| m.get("paginationToken"))
+            .and_then(|t| t.as_str())
+            .map(|s| s.to_string());
+
+        Ok((updates, pagination_token))
+    }
+
+    pub async fn get_current_profile_views(&self) -> Result<u64, LinkedinError> {
+        let res = self.client.get("/identity/wvmpCards").await?;
+        let data: Value = res.json().await?;
+
+        let views = data
+            .get("elements")
+            .and_then(|e| e.get(0))
+            .and_then(|e| e.get("value"))
+            .and_then(|v| v.get("com.linkedin.voyager.identity.me.wvmpOverview.WvmpViewersCard"))
+            .and_then(|c| c.get("insightCards"))
+            .and_then(|i| i.get(0))
+            .and_then(|i| i.get("value"))
+            .and_then(|v| {
+                v.get("com.linkedin.voyager.identity.me.wvmpOverview.WvmpSummaryInsightCard")
+            })
+            .and_then(|s| s.get("numViews"))
+            .and_then(|n| n.as_u64())
+            .unwrap_or(0);
+
+        Ok(views)
+    }
+
+    pub async fn get_school(&self, public_id: &str) -> Result<School, LinkedinError> {
+        let params = format!("?decorationId=com.linkedin.voyager.deco.organization.web.WebFullCompanyMain-12&q=universalName&universalName={public_id}");
+
+        let res = self
+            .client
+            .get(&format!("/organization/companies{params}"))
+            .await?;
+        let data: Value = res.json().await?;
+
+        if let Some(status) = data.get("status") {
+            if status != 200 {
+                return Err(LinkedinError::RequestFailed(
+                    "School request failed".to_string(),
+                ));
+            }
+        }
+
+        let school_data = data
+            .get("elements")
+            .and_then(|e| e.get(0))
+            .ok_or_else(|| LinkedinError::RequestFailed("No school data found".to_string()))?;
+
+        let name = school_data
+            .get("name")
+            .and_then(|n| n.as_str())
+            .ok_or_else(|| LinkedinError::RequestFailed("No school name found".to_string()))?;
+
+        Ok(School {
+            name: name.to_string(),
+        })
+    }
+
+    pub async fn get_company(&self, public_id: &str) -> Result<Company, LinkedinError> {
+        let params = format!("?decorationId=com.linkedin.voyager.deco.organization.web.WebFullCompanyMain-12&q=universalName&universalName={public_id}");
+
+        let res = self
+            .client
+            .get(&format!("/organization/companies{params}"))
+            .await?;
+        let data: Value = res.json().await?;
+
+        if let Some(status) = data.get("status") {
+            if status != 200 {
+                return Err(LinkedinError::RequestFailed(
+                    data.get("message")
+                        .unwrap_or(&Value::String("Unknown error".to_string()))
+                        .as_str()
+                        .unwrap()
+                        .to_string(),
+                ));
+            }
+        }
+
+        let company_data = data
+            .get("elements")
+            .and_then(|e| e.get(0))
+            .ok_or_else(|| LinkedinError::RequestFailed("No company data found".to_string()))?;
+
+        let name = company_data
+            .get("name")
+            .and_then(|n| n.as_str())
+            .ok_or_else(|| LinkedinError::RequestFailed("No company name found".to_string()))?;
+
+        Ok(Company {
+            name: name.to_string(),
+        })
+    }
+
+    pub async fn get_job(&self, job_id: &str) -> Result<JobPosting, LinkedinError> {
+        let res = self
+            .client
+            .get(&format!("/jobs/jobPostings/{job_id}?decorationId=com.linkedin.voyager.deco.jobs.web.shared.WebFullJobPosting-65"))
+            .await?;
+        if res.status() != 200 {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        let data: Value = res.json().await?;
+
+        let company_name = data
+            .get("companyDetails")
+            .and_then(|c| c.get("com.linkedin.voyager.deco.jobs.web.shared.WebJobPostingCompany"))
+            .and_then(|c| c.get("companyResolutionResult"))
+            .and_then(|c| c.get("name"))
+            .or_else(|| {
+                data.get("companyDetails")
+                    .and_then(|c| c.get("com.linkedin.voyager.jobs.JobPostingCompanyName"))
+                    .and_then(|c| c.get("companyName"))
+            })
+            .and_then(|n| n.as_str())
+            .map(|s| s.to_string());
+
+        // The posting doesn't carry its skills; the skill-match insight lists
+        // them. It is member-specific and can fail on its own, so a posting
+        // without it just has no skills.
+        let skills = self
+            .get_job_skill_match(job_id)
+            .await
+            .map(|matches| matches.into_iter().map(|m| m.skill).collect())
+            .unwrap_or_default();
+
+        Ok(JobPosting {
+            job_posting_id: job_id.to_string(),
+            entity_urn: data
+                .get("entityUrn")
+                .and_then(|u| u.as_str())
+                .map(|s| s.to_string()),
+            title: data
+                .get("title")
+                .and_then(|t| t.as_str())
+                .map(|s| s.to_string()),
+            description: data
+                .get("description")
+                .and_then(|d| d.get("text"))
+                .and_then(|t| t.as_str())
+                .map(|s| s.to_string()),
+            company_name,
+            formatted_location: data
+                .get("formattedLocation")
+                .and_then(|l| l.as_str())
+                .map(|s| s.to_string()),
+            listed_at: data
+                .get("listedAt")
+                .and_then(|l| l.as_i64())
+                .and_then(datetime_from_millis),
+            skills,
+        })
+    }
+
+    pub async fn get_job_skill_match(
+        &self,
+        job_id: &str,
+    ) -> Result<Vec<JobSkillMatch>, LinkedinError> {
+        let res = self
+            .client
+            .get(&format!("/voyagerAssessmentsDashJobSkillMatchInsight/urn%3Ali%3Afsd_jobSkillMatchInsight%3A{job_id}?decorationId=com.linkedin.voyager.dash.deco.assessments.FullJobSkillMatchInsight-17"))
+            .await?;
+        if res.status() != 200 {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        let data: Value = res.json().await?;
+
+        let mut matches = vec![];
+
+        if let Some(statuses) = data.get("skillMatchStatuses").and_then(|s| s.as_array()) {
+            for status in statuses {
+                if let Some(name) = status
+                    .get("skill")
+                    .and_then(|s| s.get("name"))
+                    .and_then(|n| n.as_str())
+                {
+                    matches.push(JobSkillMatch {
+                        skill: name.to_string(),
+                        on_profile: status
+                            .get("skillOnProfile")
+                            .and_then(|o| o.as_bool())
+                            .unwrap_or(false),
+                    });
+                }
+            }
+        }
+
+        Ok(matches)
+    }
+
+    pub async fn get_job_applicant_insights(
+        &self,
+        job_id: &str,
+    ) -> Result<JobApplicantInsights, LinkedinError> {
+        let res = self
+            .client
+            .get(&format!("/voyagerPremiumDashJobApplicantInsights/urn%3Ali%3Afsd_jobApplicantInsights%3A{job_id}"))
+            .await?;
+        if res.status() != 200 {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        let data: Value = res.json().await?;
+
+        let buckets = |key: &str, label_key: &str| -> Vec<InsightBucket> {
+            data.get(key)
+                .and_then(|d| d.as_array())
+                .map(|entries| {
+                    entries
+                        .iter()
+                        .filter_map(|entry| {
+                            Some(InsightBucket {
+                                label: entry.get(label_key)?.as_str()?.to_string(),
+                                percentage: entry.get("percentage")?.as_f64()?,
+                            })
+                        })
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        Ok(JobApplicantInsights {
+            applicant_count: data.get("applicantCount").and_then(|c| c.as_u64()),
+            applicants_in_past_day: data.get("applicantsInPastDay").and_then(|c| c.as_u64()),
+            seniority_distribution: buckets("seniorityDistribution", "seniorityName"),
+            education_distribution: buckets("degreeDistribution", "degreeName"),
+        })
+    }
+
+    pub async fn get_conversation_details(
+        &self,
+        profile_uniform_resource_name: &str,
+    ) -> Result<ConversationDetails, LinkedinError> {
+        let res = self.client.get(&format!("/messaging/conversations?keyVersion=LEGACY_INBOX&q=participants&recipients=List({profile_uniform_resource_name})")).await?;
+        let data: Value = res.json().await?;
+
+        let item = data
+            .get("elements")
+            .and_then(|e| e.get(0))
+            .ok_or_else(|| LinkedinError::RequestFailed("No conversation found".to_string()))?;
+
+        Conversation::from_voyager(item).ok_or(LinkedinError::RequestFailed("No entityUrn".into()))
+    }
+
+    pub async fn get_conversations(&self) -> Result<Vec<Conversation>, LinkedinError> {
+        self.get_conversations_page(&InboxFilter::All, None).await
+    }
+
+    pub(crate) async fn get_conversations_page(
+        &self,
+        filter: &InboxFilter,
+        created_before: Option<OffsetDateTime>,
+    ) -> Result<Vec<Conversation>, LinkedinError> {
+        let mut url = "/messaging/conversations?keyVersion=LEGACY_INBOX".to_string();
+        match filter {
+            InboxFilter::All => {}
+            InboxFilter::Unread => url.push_str("&q=search&filters=List(UNREAD)"),
+            InboxFilter::Archived => url.push_str("&q=search&filters=List(ARCHIVED)"),
+            InboxFilter::InMail => url.push_str("&q=search&filters=List(INMAIL)"),
+            InboxFilter::Participant(urn) => {
+                url.push_str(&format!("&q=participants&recipients=List({})", urn.id))
+            }
+        }
+        if let Some(created_before) = created_before {
+            let millis = created_before.unix_timestamp_nanos() / 1_000_000;
+            url.push_str(&format!("&createdBefore={millis}"));
+        }
+
+        let res = self.client.get(&url).await?;
+        if res.status() != 200 {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        let data: Value = res.json().await?;
+
+        let mut conversations = vec![];
+
+        if let Some(elements) = data.get("elements").and_then(|e| e.as_array()) {
+            for element in elements {
+                if let Some(conversation) = Conversation::from_voyager(element) {
+                    conversations.push(conversation);
+                }
+            }
+        }
+
+        Ok(conversations)
+    }
+
+    pub async fn get_conversation(
+        &self,
+        conversation_uniform_resource_name: &str,
+        created_before: Option<OffsetDateTime>,
+    ) -> Result<Vec<Message>, LinkedinError> {
+        let mut url =
+            format!("/messaging/conversations/{conversation_uniform_resource_name}/events");
+        if let Some(created_before) = created_before {
+            let millis = created_before.unix_timestamp_nanos() / 1_000_000;
+            url.push_str(&format!("?createdBefore={millis}"));
+        }
+
+        let res = self.client.get(&url).await?;
+        if res.status() != 200 {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        let data: Value = res.json().await?;
+
+        let mut messages: Vec<Message> = data
+            .get("elements")
+            .and_then(|e| e.as_array())
+            .map(|elements| elements.iter().map(Message::from_voyager).collect())
+            .unwrap_or_default();
+        messages.sort_by_key(|m| m.created_at);
+
+        Ok(messages)
+    }
+
+    pub async fn get_conversation_history(
+        &self,
+        conversation_uniform_resource_name: &str,
+        max_results: Option<usize>,
+    ) -> Result<Vec<Message>, LinkedinError> {
+        self.get_conversation_since(conversation_uniform_resource_name, None, max_results)
+            .await
+    }
+
+    /// Walk a conversation backwards from its newest message, stopping at
+    /// `since` (exclusive) or after `max_results` messages. Returned oldest
+    /// first.
+    pub(crate) async fn get_conversation_since(
+        &self,
+        conversation_uniform_resource_name: &str,
+        since: Option<OffsetDateTime>,
+        max_results: Option<usize>,
+    ) -> Result<Vec<Message>, LinkedinError> {
+        let max_results = max_results.unwrap_or(usize::MAX);
+        let mut history: Vec<Message> = vec![];
+        let mut seen = HashSet::new();
+        let mut created_before = None;
+
+        for _ in 0..MAX_REPEATED_REQUESTS {
+            let page = self
+                .get_conversation(conversation_uniform_resource_name, created_before)
+                .await?;
+            let oldest = page.iter().filter_map(|m| m.created_at).min();
+
+            // Pages overlap on purpose, so only keep events not seen yet
+            let mut found_new = false;
+            let fresh: Vec<Message> = page
+                .into_iter()
+                .filter(|m| match &m.entity_urn {
+                    Some(urn) => {
+                        let new = seen.insert(urn.clone());
+                        found_new |= new;
+                        new
+                    }
+                    None => true,
+                })
+                .filter(|m| since.is_none_or(|since| m.created_at.is_some_and(|t| t > since)))
+                .collect();
+
+            let Some(oldest) = oldest else {
+                history.splice(0..0, fresh);
+                break;
+            };
+            if !found_new {
+                // A millisecond holding more than a page of events would
+                // repeat forever; step strictly past it once before stopping
+                if created_before == Some(oldest) {
+                    break;
+                }
+                created_before = Some(oldest);
+                continue;
+            }
+
+            history.splice(0..0, fresh);
+            if history.len() >= max_results || since.is_some_and(|since| oldest <= since) {
+                break;
+            }
+            // Include the oldest millisecond again: other events sharing it
+            // may not have fit on this page
+            created_before = Some(oldest + Duration::milliseconds(1));
+        }
+
+        let excess = history.len().saturating_sub(max_results);
+        history.drain(..excess);
+        Ok(history)
+    }
+
+    pub async fn send_message(
+        &self,
+        conversation_uniform_resource_name: Option<&str>,
+        recipients: Option<Vec<String>>,
+        message_body: &str,
+    ) -> Result<SentMessage, LinkedinError> {
+        if message_body.trim().is_empty() {
+            return Err(LinkedinError::InvalidInput("Message body is empty".into()));
+        }
+
+        let message = NewMessage::builder().text(message_body).build()?;
+        self.send_rich_message(conversation_uniform_resource_name, recipients, &message)
+            .await
+    }
+
+    pub async fn send_rich_message(
+        &self,
+        conversation_uniform_resource_name: Option<&str>,
+        recipients: Option<Vec<String>>,
+        message: &NewMessage,
+    ) -> Result<SentMessage, LinkedinError> {
+        self.send_message_event(
+            conversation_uniform_resource_name,
+            recipients,
+            None,
+            message,
+        )
+        .await
+    }
+
+    pub async fn create_group_conversation(
+        &self,
+        name: &str,
+        participants: &[UniformResourceName],
+        message: &NewMessage,
+    ) -> Result<SentMessage, LinkedinError> {
+        if name.trim().is_empty() {
+            return Err(LinkedinError::InvalidInput(
+                "Conversation name is empty".into(),
+            ));
+        }
+        let mut recipients: Vec<String> = participants.iter().map(|p| p.id.clone()).collect();
+        recipients.sort();
+        recipients.dedup();
+        if recipients.len() < 2 {
+            return Err(LinkedinError::InvalidInput(
+                "A group conversation needs at least two other participants".into(),
+            ));
+        }
+
+        self.send_message_event(None, Some(recipients), Some(name), message)
+            .await
+    }
+
+    pub async fn get_conversation_participants(
+        &self,
+        conversation_uniform_resource_name: &str,
+    ) -> Result<Vec<MiniProfile>, LinkedinError> {
+        let res = self
+            .client
+            .get(&format!(
+                "/messaging/conversations/{conversation_uniform_resource_name}"
+            ))
+            .await?;
+        if res.status() != 200 {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        let data: Value = res.json().await?;
+        let conversation = Conversation::from_voyager(&data)
+            .ok_or_else(|| LinkedinError::RequestFailed("No entityUrn".into()))?;
+
+        Ok(conversation.participants)
+    }
+
+    async fn send_message_event(
         &self,
         conversation_uniform_resource_name: Option<&str>,
         recipients: Option<Vec<String>>,
-        message_body: &str,
-    ) -> Result<bool, LinkedinError> {
-        if conversation_uniform_resource_name.is_none() && recipients.is_none() {
-            return Ok(true); // Error case
+        name: Option<&str>,
+        message: &NewMessage,
+    ) -> Result<SentMessage, LinkedinError> {
+        if conversation_uniform_resource_name.is_none()
+            && recipients.as_ref().is_none_or(|r| r.is_empty())
+        {
+            return Err(LinkedinError::InvalidInput(
+                "Either a conversation or at least one recipient must be provided".into(),
+            ));
         }
 
-        if message_body.is_empty() {
-            return Ok(true); // Error case
+        // Images are sent as media attachments so they render inline; other
+        // files as plain attachments
+        let mut media_attachments = vec![];
+        let mut attachments = vec![];
+        let uploads = message
+            .images
+            .iter()
+            .map(|file| (file, MediaUploadType::MessagingPhotoAttachment))
+            .chain(
+                message
+                    .attachments
+                    .iter()
+                    .map(|file| (file, MediaUploadType::MessagingFileAttachment)),
+            );
+        for (file, upload_type) in uploads {
+            let urn = self.upload_media(upload_type, file).await?;
+            let attachment = json!({
+                "id": urn.as_str(),
+                "name": file.filename,
+                "byteSize": file.bytes.len(),
+                "mediaType": file.media_type(),
+                "reference": { "string": "" },
+            });
+            match upload_type {
+                MediaUploadType::MessagingPhotoAttachment => media_attachments.push(attachment),
+                _ => attachments.push(attachment),
+            }
         }
 
+        let attributes: Vec<Value> = message
+            .body
+            .spans
+            .iter()
+            .filter_map(|span| {
+                let kind = match &span.kind {
+                    SpanKind::ProfileMention { urn: Some(urn), .. }
+                    | SpanKind::CompanyMention { urn: Some(urn) } => {
+                        json!({ "com.linkedin.pemberly.text.Entity": { "urn": urn.as_str() } })
+                    }
+                    SpanKind::Link { url } => {
+                        json!({ "com.linkedin.pemberly.text.Hyperlink": { "url": url.as_str() } })
+                    }
+                    _ => return None,
+                };
+                Some(json!({
+                    "start": span.start,
+                    "length": span.length,
+                    "type": kind,
+                }))
+            })
+            .collect();
+
         let message_event = json!({
             "eventCreate": {
                 "value": {
                     "com.linkedin.voyager.messaging.create.MessageCreate": {
-                        "body": message_body,
-                        "attachments": [],
+                        "body": message.body.text,
+                        "attachments": attachments,
                         "attributedBody": {
-                            "text": message_body,
-                            "attributes": []
+                            "text": message.body.text,
+                            "attributes": attributes
                         },
-                        "mediaAttachments": []
+                        "mediaAttachments": media_attachments
                     }
                 }
             }
         });
 
-        let res = if let Some(conv_id) = conversation_uniform_resource_name {
-            self.client
-                .post(
-                    &format!("/messaging/conversations/{conv_id}/events?action=create"),
-                    &message_event,
-                )
-                .await?
-        } else if let Some(recips) = recipients {
-            let mut payload = message_event;
-            payload["recipients"] = json!(recips);
-            payload["subtype"] = json!("MEMBER_TO_MEMBER");
-
-            let full_payload = json!({
-                "keyVersion": "LEGACY_INBOX",
-                "conversationCreate": payload
-            });
+        let res = match (conversation_uniform_resource_name, recipients) {
+            (Some(conv_id), _) => {
+                self.client
+                    .post(
+                        &format!("/messaging/conversations/{conv_id}/events?action=create"),
+                        &message_event,
+                    )
+                    .await?
+            }
+            (None, Some(recips)) => {
+                let recips: Vec<String> = recips.iter().map(|r| recipient_id(r)).collect();
+                let mut payload = message_event;
+                payload["recipients"] = json!(recips);
+                payload["subtype"] = json!("MEMBER_TO_MEMBER");
+                if let Some(name) = name {
+                    payload["name"] = json!(name);
+                }
 
-            self.client
-                .post("/messaging/conversations?action=create", &full_payload)
-                .await?
-        } else {
-            return Ok(true); // Error case
+                let full_payload = json!({
+                    "keyVersion": "LEGACY_INBOX",
+                    "conversationCreate": payload
+                });
+
+                self.client
+                    .post("/messaging/conversations?action=create", &full_payload)
+                    .await?
+            }
+            (None, None) => {
+                return Err(LinkedinError::InvalidInput(
+                    "Either a conversation or at least one recipient must be provided".into(),
+                ))
+            }
+        };
+
+        if !res.status().is_success() {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        let data: Value = res.json().await?;
+        let value = data.get("value").unwrap_or(&data);
+        let urn_of = |key: &str| {
+            value
+                .get(key)
+                .and_then(|u| u.as_str())
+                .and_then(|u| UniformResourceName::parse(u).ok())
         };
 
-        Ok(res.status() != 201)
+        let event_urn = urn_of("eventUrn")
+            .ok_or_else(|| LinkedinError::RequestFailed("No eventUrn in response".into()))?;
+
+        let conversation_urn = urn_of("conversationUrn")
+            .or_else(|| {
+                conversation_uniform_resource_name.map(|id| UniformResourceName {
+                    namespace: "fs_conversation".into(),
+                    id: id.to_string(),
+                })
+            })
+            .or_else(|| event_conversation_urn(&event_urn))
+            .ok_or_else(|| LinkedinError::RequestFailed("No conversationUrn in response".into()))?;
+
+        Ok(SentMessage {
+            event_urn,
+            conversation_urn,
+            created_at: value
+                .get("createdAt")
+                .and_then(|t| t.as_i64())
+                .and_then(datetime_from_millis),
+        })
     }
 
     pub async fn mark_conversation_as_seen(
         &self,
         conversation_uniform_resource_name: &str,
-    ) -> Result<bool, LinkedinError> {
-        let payload = json!({
-            "patch": {
-                "$set": {
-                    "read": true
-                }
-            }
-        });
+    ) -> Result<ConversationUpdate, LinkedinError> {
+        self.update_conversation(
+            conversation_uniform_resource_name,
+            ConversationAction::MarkRead,
+        )
+        .await
+    }
 
+    pub async fn send_typing_indicator(
+        &self,
+        conversation_uniform_resource_name: &str,
+    ) -> Result<(), LinkedinError> {
         let res = self
             .client
             .post(
-                &format!("/messaging/conversations/{conversation_uniform_resource_name}"),
-                &payload,
+                &format!(
+                    "/messaging/conversations/{conversation_uniform_resource_name}?action=typing"
+                ),
+                &json!({}),
             )
             .await?;
-        Ok(res.status() != 200)
+        if !res.status().is_success() {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        Ok(())
+    }
+
+    pub async fn mark_message_as_seen(
+        &self,
+        message: &UniformResourceName,
+    ) -> Result<(), LinkedinError> {
+        let conversation = event_conversation_urn(message).ok_or_else(|| {
+            LinkedinError::InvalidURN(format!("Not a message event urn: {message}"))
+        })?;
+
+        let res = self
+            .client
+            .post(
+                &format!(
+                    "/messaging/conversations/{}/receipts?action=create",
+                    conversation.id
+                ),
+                &json!({ "eventUrn": message.as_str() }),
+            )
+            .await?;
+        if !res.status().is_success() {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_seen_receipts(
+        &self,
+        conversation_uniform_resource_name: &str,
+    ) -> Result<Vec<SeenReceipt>, LinkedinError> {
+        let res = self
+            .client
+            .get(&format!(
+                "/messaging/conversations/{conversation_uniform_resource_name}/receipts"
+            ))
+            .await?;
+        if res.status() != 200 {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        let data: Value = res.json().await?;
+
+        Ok(data
+            .get("elements")
+            .and_then(|e| e.as_array())
+            .map(|elements| elements.iter().map(SeenReceipt::from_voyager).collect())
+            .unwrap_or_default())
+    }
+
+    pub async fn update_conversation(
+        &self,
+        conversation_uniform_resource_name: &str,
+        action: ConversationAction,
+    ) -> Result<ConversationUpdate, LinkedinError> {
+        if conversation_uniform_resource_name.is_empty() {
+            return Err(LinkedinError::InvalidInput(
+                "Conversation id is empty".into(),
+            ));
+        }
+        if let ConversationAction::Rename(name) = &action {
+            if name.trim().is_empty() {
+                return Err(LinkedinError::InvalidInput(
+                    "Conversation name is empty".into(),
+                ));
+            }
+        }
+
+        let url = format!("/messaging/conversations/{conversation_uniform_resource_name}");
+        let res = match &action {
+            ConversationAction::Delete => self.client.delete(&url).await?,
+            ConversationAction::AddParticipants(participants)
+            | ConversationAction::RemoveParticipants(participants) => {
+                if participants.is_empty() {
+                    return Err(LinkedinError::InvalidInput("No participants given".into()));
+                }
+                let ids: Vec<String> = participants.iter().map(|p| p.id.clone()).collect();
+                let (added, removed) = match &action {
+                    ConversationAction::AddParticipants(_) => (ids, vec![]),
+                    _ => (vec![], ids),
+                };
+                self.client
+                    .post(
+                        &format!("{url}?action=changeParticipants"),
+                        &json!({
+                            "addedParticipants": added,
+                            "removedParticipants": removed,
+                        }),
+                    )
+                    .await?
+            }
+            ConversationAction::Leave => {
+                self.client
+                    .post(&format!("{url}?action=leave"), &json!({}))
+                    .await?
+            }
+            patched => {
+                let payload = patched.patch().ok_or_else(|| {
+                    LinkedinError::InvalidInput("Nothing to update on the conversation".into())
+                })?;
+                self.client.post(&url, &payload).await?
+            }
+        };
+        if !res.status().is_success() {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        // Most actions are answered with an empty body
+        let body = res.text().await?;
+        let data: Value = serde_json::from_str(&body).unwrap_or_default();
+
+        Ok(ConversationUpdate::from_voyager(
+            UniformResourceName {
+                namespace: "fs_conversation".into(),
+                id: conversation_uniform_resource_name.to_string(),
+            },
+            action,
+            &data,
+        ))
     }
 
     pub async fn get_user_profile(&self) -> Result<Value, LinkedinError> {
@@ -725,6 +1686,16 @@ impl LinkedinInner {
         start: usize,
         limit: usize,
     ) -> Result<Vec<Invitation>, LinkedinError> {
+        Ok(self.get_invitations_page(start, limit).await?.0)
+    }
+
+    /// A page of received invitations, with the number of raw elements
+    /// returned so callers can page past elements that failed to parse.
+    pub(crate) async fn get_invitations_page(
+        &self,
+        start: usize,
+        limit: usize,
+    ) -> Result<(Vec<Invitation>, usize), LinkedinError> {
         let params =
             format!("?start={start}&count={limit}&includeInsights=true&q=receivedInvitation");
 
@@ -734,27 +1705,137 @@ impl LinkedinInner {
             .await?;
 
         if res.status() != 200 {
-            return Ok(vec![]);
+            return Err(LinkedinError::from_status(res.status()));
         }
 
         let data: Value = res.json().await?;
 
-        let mut invitations = vec![];
+        Ok(parse_elements(&data, Invitation::from_voyager))
+    }
 
-        if let Some(elements) = data.get("elements").and_then(|e| e.as_array()) {
-            for element in elements {
-                if let Some(invitation) = element.get("invitation") {
-                    if let (Some(entity_urn), Some(shared_secret)) = (
-                        invitation.get("entityUrn").and_then(|u| u.as_str()),
-                        invitation.get("sharedSecret").and_then(|s| s.as_str()),
-                    ) {
-                        invitations.push(Invitation {
-                            entity_urn: Some(entity_urn.to_string()),
-                            shared_secret: shared_secret.to_string(),
-                        });
-                    }
+    pub async fn send_invitation(
+        &self,
+        public_id: Option<&str>,
+        urn: Option<&UniformResourceName>,
+        note: Option<&str>,
+    ) -> Result<InvitationResult, LinkedinError> {
+        let note = note.map(str::trim).filter(|n| !n.is_empty());
+        if let Some(note) = note {
+            if note.chars().count() > MAX_INVITATION_NOTE_LENGTH {
+                return Err(LinkedinError::InvalidInput(format!(
+                    "Invitation note is longer than {MAX_INVITATION_NOTE_LENGTH} characters"
+                )));
+            }
+        }
+
+        let profile_urn = match (urn, public_id) {
+            (Some(urn), _) => urn.clone(),
+            (None, Some(public_id)) => self.get_profile_urn(public_id).await?,
+            (None, None) => {
+                return Err(LinkedinError::InvalidInput(
+                    "public_id or uniform_resource_name required".into(),
+                ))
+            }
+        };
+
+        let mut payload = json!({
+            "trackingId": tracking_id(),
+            "invitee": {
+                "com.linkedin.voyager.growth.invitation.InviteeProfile": {
+                    "profileId": profile_urn.id
                 }
             }
+        });
+        if let Some(note) = note {
+            payload["customMessage"] = json!(note);
+        }
+
+        let res = self
+            .client
+            .post("/growth/normInvitations", &payload)
+            .await?;
+        let status = res.status();
+        if status.is_success() {
+            return Ok(InvitationResult::Sent);
+        }
+
+        let data: Value = res.json().await.unwrap_or_default();
+        let code = data
+            .get("code")
+            .or_else(|| data.get("data").and_then(|d| d.get("code")))
+            .and_then(|c| c.as_str())
+            .unwrap_or_default();
+
+        InvitationResult::from_error_code(code).ok_or_else(|| LinkedinError::from_status(status))
+    }
+
+    /// Resolve a public profile id to the member's profile URN.
+    pub(crate) async fn get_profile_urn(
+        &self,
+        public_id: &str,
+    ) -> Result<UniformResourceName, LinkedinError> {
+        let res = self
+            .client
+            .get(&format!("/identity/profiles/{}", encode(public_id)))
+            .await?;
+        if res.status() != 200 {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        let data: Value = res.json().await?;
+        let urn = data
+            .get("miniProfile")
+            .and_then(|m| m.get("entityUrn"))
+            .or_else(|| data.get("entityUrn"))
+            .and_then(|u| u.as_str())
+            .ok_or_else(|| LinkedinError::RequestFailed("No profile urn in response".into()))?;
+
+        UniformResourceName::parse(urn)
+    }
+
+    pub async fn get_sent_invitations(
+        &self,
+        start: usize,
+        limit: usize,
+    ) -> Result<Vec<SentInvitation>, LinkedinError> {
+        Ok(self.get_sent_invitations_page(start, limit).await?.0)
+    }
+
+    /// A page of sent invitations, with the number of raw elements returned
+    /// so callers can page past elements that failed to parse.
+    async fn get_sent_invitations_page(
+        &self,
+        start: usize,
+        limit: usize,
+    ) -> Result<(Vec<SentInvitation>, usize), LinkedinError> {
+        let res = self
+            .client
+            .get(&format!(
+                "/relationships/sentInvitationViewsV2?start={start}&count={limit}&invitationType=CONNECTION&q=invitationType"
+            ))
+            .await?;
+        if res.status() != 200 {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        let data: Value = res.json().await?;
+
+        Ok(parse_elements(&data, SentInvitation::from_voyager))
+    }
+
+    pub async fn get_all_sent_invitations(&self) -> Result<Vec<SentInvitation>, LinkedinError> {
+        let mut invitations = vec![];
+        let mut start = 0;
+
+        for _ in 0..MAX_REPEATED_REQUESTS {
+            let (page, returned) = self
+                .get_sent_invitations_page(start, MAX_INVITATION_COUNT)
+                .await?;
+            invitations.extend(page);
+            start += returned;
+            if returned < MAX_INVITATION_COUNT {
+                break;
+            }
         }
 
         Ok(invitations)
@@ -762,28 +1843,32 @@ impl LinkedinInner {
 
     pub async fn reply_invitation(
         &self,
-        invitation_entity_urn: &str,
-        invitation_shared_secret: &str,
-        action: &str,
-    ) -> Result<bool, LinkedinError> {
-        let urn = UniformResourceName::parse(invitation_entity_urn)?;
-
+        invitation: &UniformResourceName,
+        shared_secret: &str,
+        action: InvitationAction,
+    ) -> Result<(), LinkedinError> {
         let payload = json!({
-            "invitationId": urn.id,
-            "invitationSharedSecret": invitation_shared_secret,
+            "invitationId": invitation.id,
+            "invitationSharedSecret": shared_secret,
             "isGenericInvitation": false
         });
 
-        let invitation_id = urn.id;
         let res = self
             .client
             .post(
-                &format!("/relationships/invitations/{invitation_id}?action={action}"),
+                &format!(
+                    "/relationships/invitations/{}?action={}",
+                    invitation.id,
+                    action.as_str()
+                ),
                 &payload,
             )
             .await?;
+        if !res.status().is_success() {
+            return Err(LinkedinError::from_status(res.status()));
+        }
 
-        Ok(res.status() == 200)
+        Ok(())
     }
 
     pub async fn remove_connection(&self, public_profile_id: &str) -> Result<bool, LinkedinError> {
@@ -798,6 +1883,105 @@ impl LinkedinInner {
         Ok(res.status() != 200)
     }
 
+    /// Follow a member, company, school or hashtag.
+    pub async fn follow(&self, entity: &UniformResourceName) -> Result<(), LinkedinError> {
+        self.set_following(entity, "followByEntityUrn").await
+    }
+
+    pub async fn unfollow(&self, entity: &UniformResourceName) -> Result<(), LinkedinError> {
+        self.set_following(entity, "unfollowByEntityUrn").await
+    }
+
+    async fn set_following(
+        &self,
+        entity: &UniformResourceName,
+        action: &str,
+    ) -> Result<(), LinkedinError> {
+        if FollowKind::from_urn(entity).is_none() {
+            return Err(LinkedinError::InvalidURN(format!(
+                "Not a followable entity: {entity}"
+            )));
+        }
+
+        let res = self
+            .client
+            .post(
+                &format!("/feed/follows?action={action}"),
+                &json!({ "urn": entity.as_str() }),
+            )
+            .await?;
+        if !res.status().is_success() {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        Ok(())
+    }
+
+    pub async fn is_following(&self, entity: &UniformResourceName) -> Result<bool, LinkedinError> {
+        if FollowKind::from_urn(entity).is_none() {
+            return Err(LinkedinError::InvalidURN(format!(
+                "Not a followable entity: {entity}"
+            )));
+        }
+
+        let res = self
+            .client
+            .get(&format!("/feed/followingInfo/{}", encode(&entity.as_str())))
+            .await?;
+        if res.status() != 200 {
+            return Err(LinkedinError::from_status(res.status()));
+        }
+
+        let data: Value = res.json().await?;
+        data.get("data")
+            .unwrap_or(&data)
+            .get("following")
+            .and_then(|f| f.as_bool())
+            .ok_or_else(|| LinkedinError::RequestFailed("No following state in response".into()))
+    }
+
+    /// Every entity of `kind` the current user follows.
+    pub async fn get_following(
+        &self,
+        kind: FollowKind,
+    ) -> Result<Vec<FollowedEntity>, LinkedinError> {
+        let mut following = vec![];
+        let mut start = 0;
+
+        for _ in 0..MAX_REPEATED_REQUESTS {
+            let res = self
+                .client
+                .get(&format!(
+                    "/identity/profiles/me/following?q=followedEntities&entityType={}&start={start}&count={MAX_FOLLOWING_COUNT}",
+                    kind.as_str()
+                ))
+                .await?;
+            if res.status() != 200 {
+                return Err(LinkedinError::from_status(res.status()));
+            }
+
+            let data: Value = res.json().await?;
+            let elements = data
+                .get("elements")
+                .and_then(|e| e.as_array())
+                .cloned()
+                .unwrap_or_default();
+            following.extend(
+                elements
+                    .iter()
+                    .filter_map(FollowedEntity::from_voyager)
+                    .filter(|entity| entity.kind == kind),
+            );
+
+            start += elements.len();
+            if elements.len() < MAX_FOLLOWING_COUNT {
+                break;
+            }
+        }
+
+        Ok(following)
+    }
+
     pub async fn get_profile_privacy_settings(
         &self,
         public_profile_id: &str,
@@ -882,18 +2066,13 @@ impl LinkedinInner {
             .await?;
 
         if res.status() != 200 {
-            return Ok(NetworkInfo { followers_count: 0 });
+            return Err(LinkedinError::from_status(res.status()));
         }
 
         let data: Value = res.json().await?;
 
-        let followers_count = data
-            .get("data")
-            .and_then(|d| d.get("followersCount"))
-            .and_then(|f| f.as_u64())
-            .unwrap_or(0);
-
-        Ok(NetworkInfo { followers_count })
+        NetworkInfo::from_voyager(&data)
+            .ok_or_else(|| LinkedinError::RequestFailed("No network info in response".into()))
     }
 
     pub async fn stub_people_search(
@@ -919,3 +2098,123 @@ impl LinkedinInner {
         res.json().await.map_err(Into::into)
     }
 }
+
+/// Fill in the blended-search parameters a caller didn't set.
+fn with_search_defaults(
+    mut params: HashMap<String, String>,
+    count: usize,
+) -> HashMap<String, String> {
+    let default_params = vec![
+        ("count".to_string(), count.to_string()),
+        ("filters".to_string(), "List()".to_string()),
+        ("origin".to_string(), "GLOBAL_SEARCH_HEADER".to_string()),
+        ("q".to_string(), "all".to_string()),
+        ("start".to_string(), "0".to_string()),
+        ("queryContext".to_string(), "List(spellCorrectionEnabled->true,relatedSearchesEnabled->true,kcardTypes->PROFILE|COMPANY)".to_string()),
+    ];
+
+    for (key, value) in default_params {
+        params.entry(key).or_insert(value);
+    }
+    params
+}
+
+/// Recipients and participants are always sent as bare profile ids, so a
+/// full `urn:li:fsd_profile:…` is reduced to its id.
+fn recipient_id(recipient: &str) -> String {
+    UniformResourceName::parse(recipient)
+        .map(|urn| urn.id)
+        .unwrap_or_else(|_| recipient.to_string())
+}
+
+/// Blended-search parameters for a people search.
+fn people_search_params(params: &SearchPeopleParams) -> HashMap<String, String> {
+    let mut filters = vec!["resultType->PEOPLE".to_string()];
+
+    if let Some(connection_of) = &params.connection_of {
+        filters.push(format!("connectionOf->{connection_of}"));
+    }
+    if let Some(network_depth) = &params.network_depth {
+        filters.push(format!("network->{network_depth}"));
+    }
+    if let Some(regions) = &params.regions {
+        filters.push(format!("geoRegion->{}", regions.join("|")));
+    }
+    if let Some(industries) = &params.industries {
+        filters.push(format!("industry->{}", industries.join("|")));
+    }
+    if let Some(current_company) = &params.current_company {
+        filters.push(format!("currentCompany->{}", current_company.join("|")));
+    }
+    if let Some(past_companies) = &params.past_companies {
+        filters.push(format!("pastCompany->{}", past_companies.join("|")));
+    }
+    if let Some(profile_languages) = &params.profile_languages {
+        filters.push(format!("profileLanguage->{}", profile_languages.join("|")));
+    }
+    if let Some(nonprofit_interests) = &params.nonprofit_interests {
+        filters.push(format!(
+            "nonprofitInterest->{}",
+            nonprofit_interests.join("|")
+        ));
+    }
+    if let Some(schools) = &params.schools {
+        filters.push(format!("schools->{}", schools.join("|")));
+    }
+
+    let mut search_params = HashMap::new();
+    search_params.insert(
+        "filters".to_string(),
+        format!("List({})", filters.join(",")),
+    );
+
+    if let Some(keywords) = &params.keywords {
+        search_params.insert("keywords".to_string(), keywords.clone());
+    }
+
+    search_params
+}
+
+/// Parse a people search result. Returns `None` without a public identifier.
+fn person_from_search(item: &Value) -> Option<PersonSearchResult> {
+    let public_id = item.get("publicIdentifier")?.as_str()?;
+    let urn_id = item
+        .get("targetUrn")
+        .and_then(|u| u.as_str())
+        .and_then(|s| UniformResourceName::parse(s).ok())
+        .map(|urn| urn.id)
+        .unwrap_or_default();
+    let distance = item
+        .get("memberDistance")
+        .and_then(|d| d.get("value"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("");
+
+    let text_of = |key: &str| {
+        item.get(key)
+            .and_then(|t| t.get("text"))
+            .and_then(|t| t.as_str())
+            .map(|s| s.to_string())
+    };
+
+    Some(PersonSearchResult {
+        urn_id,
+        public_id: public_id.to_string(),
+        distance: distance.to_string(),
+        name: text_of("title"),
+        headline: text_of("headline"),
+    })
+}
+
+/// Parse a collection's `elements`, returning the parsed items and the number
+/// of raw elements. Paging offsets must advance by the raw count, since
+/// elements that fail to parse are dropped.
+fn parse_elements<T>(data: &Value, parse: impl Fn(&Value) -> Option<T>) -> (Vec<T>, usize) {
+    let elements = data
+        .get("elements")
+        .and_then(|e| e.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    (elements.iter().filter_map(parse).collect(), elements.len())
+}
@@ -0,0 +1,465 @@
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use reqwest::header::{self, HeaderMap, HeaderValue};
+use reqwest::{Client as ReqwestClient, Response, Url};
+use serde_json::Value;
+use time::OffsetDateTime;
+use tokio::time::sleep;
+
+use crate::inbox::InboxFilter;
+use crate::linkedin::LinkedinInner;
+use crate::types::{
+    event_conversation_urn, Conversation, Message, SeenReceipt, UniformResourceName,
+};
+use crate::LinkedinError;
+
+pub const REALTIME_URL: &str = "https://realtime.www.linkedin.com/realtime/connect";
+
+const DECORATED_EVENT: &str = "com.linkedin.realtimefrontend.DecoratedEvent";
+const DEFAULT_MAX_RETRIES: u32 = 5;
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(60);
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(30);
+const DEFAULT_STREAM_RETRY_INTERVAL: Duration = Duration::from_secs(300);
+
+/// A single server-sent event
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SseEvent {
+    pub event: Option<String>,
+    pub data: String,
+    pub id: Option<String>,
+}
+
+impl SseEvent {
+    /// Parse one event block (the lines between blank lines). Returns `None` for
+    /// blocks without data, such as comment-only heartbeats.
+    pub fn parse(block: &str) -> Option<Self> {
+        let mut event = SseEvent::default();
+        let mut data = vec![];
+
+        for line in block.lines() {
+            if line.is_empty() || line.starts_with(':') {
+                continue;
+            }
+            let (field, value) = line.split_once(':').unwrap_or((line, ""));
+            let value = value.strip_prefix(' ').unwrap_or(value);
+            match field {
+                "event" => event.event = Some(value.to_string()),
+                "data" => data.push(value),
+                "id" => event.id = Some(value.to_string()),
+                _ => {}
+            }
+        }
+
+        if data.is_empty() {
+            return None;
+        }
+        event.data = data.join("\n");
+        Some(event)
+    }
+}
+
+/// A reconnecting server-sent event stream.
+///
+/// Dropped connections are retried with exponential backoff, resuming from the
+/// last event id. `next` fails with the last error once `max_retries`
+/// consecutive attempts have failed, or straight away when the stream is
+/// refused as unauthorized.
+pub struct EventSource {
+    client: ReqwestClient,
+    url: Url,
+    headers: HeaderMap,
+    response: Option<Response>,
+    buffer: Vec<u8>,
+    last_event_id: Option<String>,
+    failures: u32,
+    last_error: Option<LinkedinError>,
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl EventSource {
+    pub fn new(client: ReqwestClient, url: Url) -> Self {
+        Self {
+            client,
+            url,
+            headers: HeaderMap::new(),
+            response: None,
+            buffer: vec![],
+            last_event_id: None,
+            failures: 0,
+            last_error: None,
+            max_retries: DEFAULT_MAX_RETRIES,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+        }
+    }
+
+    /// Extra headers sent with every connection attempt
+    pub fn headers(mut self, headers: HeaderMap) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Delay before the first retry, doubling up to `max` on each further failure
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    pub async fn next(&mut self) -> Result<SseEvent, LinkedinError> {
+        loop {
+            if let Some(event) = self.take_event() {
+                self.failures = 0;
+                if event.id.is_some() {
+                    self.last_event_id = event.id.clone();
+                }
+                return Ok(event);
+            }
+
+            let Some(response) = self.response.as_mut() else {
+                self.connect().await?;
+                continue;
+            };
+
+            match response.chunk().await {
+                Ok(Some(chunk)) => self.buffer.extend_from_slice(&chunk),
+                Ok(None) => self.disconnect(None),
+                Err(e) => self.disconnect(Some(e.into())),
+            }
+        }
+    }
+
+    async fn connect(&mut self) -> Result<(), LinkedinError> {
+        loop {
+            if self.failures > self.max_retries {
+                return Err(self.last_error.take().unwrap_or_else(|| {
+                    LinkedinError::RequestFailed(format!(
+                        "Event stream failed after {} retries",
+                        self.max_retries
+                    ))
+                }));
+            }
+            if self.failures > 0 {
+                let backoff = self
+                    .initial_backoff
+                    .saturating_mul(1 << (self.failures - 1).min(16))
+                    .min(self.max_backoff);
+                sleep(backoff).await;
+            }
+
+            let mut request = self
+                .client
+                .get(self.url.clone())
+                .headers(self.headers.clone())
+                .header(header::ACCEPT, "text/event-stream");
+            if let Some(id) = &self.last_event_id {
+                request = request.header("last-event-id", HeaderValue::from_str(id)?);
+            }
+
+            match request.send().await {
+                Ok(response) if response.status().is_success() => {
+                    self.response = Some(response);
+                    return Ok(());
+                }
+                Ok(response) => {
+                    let error = LinkedinError::from_status(response.status());
+                    if matches!(error, LinkedinError::Unauthorized(_)) {
+                        return Err(error);
+                    }
+                    self.disconnect(Some(error));
+                }
+                Err(e) => self.disconnect(Some(e.into())),
+            }
+        }
+    }
+
+    /// Drop the current connection, counting it as a failure.
+    fn disconnect(&mut self, error: Option<LinkedinError>) {
+        self.response = None;
+        self.buffer.clear();
+        self.failures += 1;
+        if error.is_some() {
+            self.last_error = error;
+        }
+    }
+
+    /// Pop the next complete event out of the buffer, skipping data-less blocks.
+    fn take_event(&mut self) -> Option<SseEvent> {
+        loop {
+            let end = self.buffer.windows(2).position(|w| w == b"\n\n");
+            let crlf_end = self.buffer.windows(4).position(|w| w == b"\r\n\r\n");
+            let (end, separator) = match (end, crlf_end) {
+                (Some(lf), Some(crlf)) if crlf < lf => (crlf, 4),
+                (Some(lf), _) => (lf, 2),
+                (None, Some(crlf)) => (crlf, 4),
+                (None, None) => return None,
+            };
+
+            let block: Vec<u8> = self.buffer.drain(..end + separator).collect();
+            if let Some(event) = SseEvent::parse(&String::from_utf8_lossy(&block[..end])) {
+                return Some(event);
+            }
+        }
+    }
+}
+
+/// A typed realtime messaging event
+#[derive(Debug, Clone, PartialEq)]
+pub enum RealtimeEvent {
+    NewMessage(Box<Message>),
+    ReadReceipt(SeenReceipt),
+    Typing {
+        conversation_urn: Option<UniformResourceName>,
+        from: Option<UniformResourceName>,
+    },
+    Reaction {
+        event_urn: Option<UniformResourceName>,
+        actor: Option<UniformResourceName>,
+        emoji: String,
+        added: bool,
+    },
+    ConversationUpdate(Box<Conversation>),
+}
+
+impl RealtimeEvent {
+    /// Parse the data of a realtime `DecoratedEvent`. Heartbeats and topics
+    /// this crate doesn't model return `None`.
+    pub fn from_sse(data: &str) -> Option<Self> {
+        let value: Value = serde_json::from_str(data).ok()?;
+        let decorated = value.get(DECORATED_EVENT)?;
+        let topic = decorated.get("topic")?.as_str()?;
+        let payload = decorated.get("payload")?;
+        let urn = |value: &Value, key: &str| {
+            value
+                .get(key)
+                .and_then(|u| u.as_str())
+                .and_then(|u| UniformResourceName::parse(u).ok())
+        };
+
+        if topic.contains("messageSeenReceiptsTopic") {
+            Some(RealtimeEvent::ReadReceipt(SeenReceipt::from_voyager(
+                payload,
+            )))
+        } else if topic.contains("typingIndicatorsTopic") {
+            Some(RealtimeEvent::Typing {
+                conversation_urn: urn(payload, "conversation"),
+                from: urn(payload, "fromEntity"),
+            })
+        } else if topic.contains("messageReactionSummariesTopic") {
+            Some(RealtimeEvent::Reaction {
+                event_urn: urn(payload, "eventUrn"),
+                actor: urn(payload, "actorMiniProfileUrn"),
+                emoji: payload
+                    .get("reactionSummary")
+                    .and_then(|s| s.get("emoji"))
+                    .and_then(|e| e.as_str())?
+                    .to_string(),
+                added: payload
+                    .get("reactionAdded")
+                    .and_then(|a| a.as_bool())
+                    .unwrap_or(true),
+            })
+        } else if topic.contains("messagesTopic") {
+            let event = payload.get("event").unwrap_or(payload);
+            Some(RealtimeEvent::NewMessage(Box::new(Message::from_voyager(
+                event,
+            ))))
+        } else if topic.contains("conversationsTopic") {
+            let conversation = payload.get("conversation").unwrap_or(payload);
+            Conversation::from_voyager(conversation)
+                .map(|c| RealtimeEvent::ConversationUpdate(Box::new(c)))
+        } else {
+            None
+        }
+    }
+}
+
+/// Last activity per conversation, turning inbox polls into
+/// `ConversationUpdate` events without repeating what the stream delivered.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityTracker {
+    last_activity: HashMap<String, Option<OffsetDateTime>>,
+    primed: bool,
+}
+
+impl ActivityTracker {
+    /// Note activity delivered on the stream
+    pub fn record(&mut self, event: &RealtimeEvent) {
+        match event {
+            RealtimeEvent::NewMessage(message) => {
+                if let Some(conversation) =
+                    message.entity_urn.as_ref().and_then(event_conversation_urn)
+                {
+                    self.last_activity
+                        .insert(conversation.id, message.created_at);
+                }
+            }
+            RealtimeEvent::ConversationUpdate(conversation) => {
+                self.last_activity
+                    .insert(conversation.id.clone(), conversation.last_activity_at);
+            }
+            _ => {}
+        }
+    }
+
+    /// Updates for the conversations on an inbox page whose activity changed.
+    /// The first page only records a baseline.
+    pub fn changes(&mut self, conversations: Vec<Conversation>) -> Vec<RealtimeEvent> {
+        let mut changed = vec![];
+        for conversation in conversations {
+            let previous = self
+                .last_activity
+                .insert(conversation.id.clone(), conversation.last_activity_at);
+            if self.primed && previous != Some(conversation.last_activity_at) {
+                changed.push(RealtimeEvent::ConversationUpdate(Box::new(conversation)));
+            }
+        }
+        self.primed = true;
+        changed
+    }
+
+    pub fn is_primed(&self) -> bool {
+        self.primed
+    }
+}
+
+/// Subscription to the realtime messaging stream.
+///
+/// Falls back to polling the inbox once the stream can't be reconnected, and
+/// tries the stream again every `stream_retry_interval`. In polling mode only
+/// `ConversationUpdate` events are produced. An unauthorized stream is
+/// returned as an error rather than polled around.
+pub struct Subscription {
+    inner: LinkedinInner,
+    endpoint: Url,
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    poll_interval: Duration,
+    stream_retry_interval: Duration,
+    source: Option<EventSource>,
+    /// When the stream last failed, while polling
+    polling_since: Option<Instant>,
+    activity: ActivityTracker,
+    pending: VecDeque<RealtimeEvent>,
+}
+
+impl Subscription {
+    pub(crate) fn new(inner: LinkedinInner) -> Self {
+        Self {
+            inner,
+            endpoint: Url::parse(REALTIME_URL).expect("valid realtime url"),
+            max_retries: DEFAULT_MAX_RETRIES,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            stream_retry_interval: DEFAULT_STREAM_RETRY_INTERVAL,
+            source: None,
+            polling_since: None,
+            activity: ActivityTracker::default(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Connect to a different event stream endpoint
+    pub fn endpoint(mut self, endpoint: Url) -> Self {
+        self.endpoint = endpoint;
+        self
+    }
+
+    /// Consecutive failed reconnects before falling back to polling
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Delay between inbox polls once the stream is unavailable
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Delay before trying the event stream again while polling
+    pub fn stream_retry_interval(mut self, stream_retry_interval: Duration) -> Self {
+        self.stream_retry_interval = stream_retry_interval;
+        self
+    }
+
+    pub fn is_polling(&self) -> bool {
+        self.polling_since.is_some()
+    }
+
+    pub async fn next(&mut self) -> Result<RealtimeEvent, LinkedinError> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Ok(event);
+            }
+
+            if let Some(since) = self.polling_since {
+                if since.elapsed() < self.stream_retry_interval {
+                    self.poll().await?;
+                    continue;
+                }
+                self.polling_since = None;
+            }
+
+            if self.source.is_none() {
+                self.source = Some(
+                    EventSource::new(self.inner.client.client.clone(), self.endpoint.clone())
+                        .headers(self.inner.client.auth_headers()?)
+                        .max_retries(self.max_retries)
+                        .backoff(self.initial_backoff, self.max_backoff),
+                );
+            }
+            let source = self.source.as_mut().expect("source was just set");
+
+            match source.next().await {
+                Ok(sse) => {
+                    if let Some(event) = RealtimeEvent::from_sse(&sse.data) {
+                        self.activity.record(&event);
+                        return Ok(event);
+                    }
+                }
+                Err(error @ LinkedinError::Unauthorized(_)) => {
+                    self.source = None;
+                    return Err(error);
+                }
+                Err(_) => {
+                    self.source = None;
+                    self.polling_since = Some(Instant::now());
+                }
+            }
+        }
+    }
+
+    /// Fetch the first inbox page and queue conversations with new activity. The
+    /// first poll only records a baseline.
+    async fn poll(&mut self) -> Result<(), LinkedinError> {
+        if self.activity.is_primed() {
+            sleep(self.poll_interval).await;
+        }
+
+        let conversations = self
+            .inner
+            .get_conversations_page(&InboxFilter::All, None)
+            .await?;
+        self.pending.extend(self.activity.changes(conversations));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+use serde::{Deserialize, Serialize};
+
+use crate::linkedin::LinkedinInner;
+use crate::types::{Invitation, InvitationAction, InvitationType};
+use crate::LinkedinError;
+
+const PAGE_SIZE: usize = 100;
+const MAX_PAGES: usize = 50;
+
+/// Rules for bulk-replying to received connection invitations.
+///
+/// An invitation is accepted when its sender passes every configured check;
+/// all other connection invitations are ignored. Page, event, newsletter and
+/// follow invitations are left alone, as is everything when no rule is set.
+#[derive(Debug, Clone, Default)]
+pub struct TriageRules {
+    min_shared_connections: Option<u64>,
+    companies: Vec<String>,
+    headline_keywords: Vec<String>,
+    dry_run: bool,
+}
+
+/// What triage did with one invitation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TriageDecision {
+    Accept,
+    Ignore,
+    /// Not a connection invitation, or no rules configured
+    Skip,
+}
+
+/// One line of a [`TriageReport`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TriageEntry {
+    pub invitation: Invitation,
+    pub decision: TriageDecision,
+    /// Why the rules reached this decision
+    pub reason: String,
+    /// Set when replying failed; the decision was not applied
+    pub error: Option<String>,
+}
+
+/// Audit trail of a triage run
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TriageReport {
+    pub dry_run: bool,
+    pub entries: Vec<TriageEntry>,
+}
+
+impl TriageReport {
+    pub fn accepted(&self) -> impl Iterator<Item = &TriageEntry> {
+        self.with_decision(TriageDecision::Accept)
+    }
+
+    pub fn ignored(&self) -> impl Iterator<Item = &TriageEntry> {
+        self.with_decision(TriageDecision::Ignore)
+    }
+
+    pub fn failed(&self) -> impl Iterator<Item = &TriageEntry> {
+        self.entries.iter().filter(|e| e.error.is_some())
+    }
+
+    fn with_decision(&self, decision: TriageDecision) -> impl Iterator<Item = &TriageEntry> {
+        self.entries
+            .iter()
+            .filter(move |e| e.decision == decision && e.error.is_none())
+    }
+}
+
+impl TriageRules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require at least this many shared connections with the sender
+    pub fn min_shared_connections(mut self, count: u64) -> Self {
+        self.min_shared_connections = Some(count);
+        self
+    }
+
+    /// Require the sender's headline to mention one of these companies
+    pub fn company(mut self, company: &str) -> Self {
+        self.companies.push(company.to_lowercase());
+        self
+    }
+
+    /// Require the sender's headline to contain one of these keywords
+    pub fn headline_keyword(mut self, keyword: &str) -> Self {
+        self.headline_keywords.push(keyword.to_lowercase());
+        self
+    }
+
+    /// Decide without replying to anything
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Decide what to do with an invitation, and why.
+    pub fn decide(&self, invitation: &Invitation) -> (TriageDecision, String) {
+        if invitation.invitation_type != InvitationType::Connection {
+            return (
+                TriageDecision::Skip,
+                format!("{:?} invitation", invitation.invitation_type),
+            );
+        }
+
+        let headline = invitation
+            .sender
+            .as_ref()
+            .and_then(|s| s.occupation.as_deref())
+            .unwrap_or_default()
+            .to_lowercase();
+        let mut reasons = vec![];
+
+        if let Some(min) = self.min_shared_connections {
+            if invitation.shared_connections < min {
+                return (
+                    TriageDecision::Ignore,
+                    format!(
+                        "{} shared connections, fewer than {min}",
+                        invitation.shared_connections
+                    ),
+                );
+            }
+            reasons.push(format!(
+                "{} shared connections",
+                invitation.shared_connections
+            ));
+        }
+
+        for (patterns, label) in [
+            (&self.companies, "company"),
+            (&self.headline_keywords, "headline keyword"),
+        ] {
+            if patterns.is_empty() {
+                continue;
+            }
+            match patterns.iter().find(|p| headline.contains(p.as_str())) {
+                Some(pattern) => reasons.push(format!("{label} \"{pattern}\"")),
+                None => {
+                    return (
+                        TriageDecision::Ignore,
+                        format!("headline matches no {label}"),
+                    )
+                }
+            }
+        }
+
+        // Without any rule there is nothing to accept on
+        if reasons.is_empty() {
+            return (TriageDecision::Skip, "no rules configured".into());
+        }
+        (TriageDecision::Accept, reasons.join(", "))
+    }
+
+    pub(crate) async fn apply(&self, inner: &LinkedinInner) -> Result<TriageReport, LinkedinError> {
+        // Read everything first: replying shifts later pages
+        let mut invitations = vec![];
+        let mut start = 0;
+        for _ in 0..MAX_PAGES {
+            let (page, returned) = inner.get_invitations_page(start, PAGE_SIZE).await?;
+            invitations.extend(page);
+            start += returned;
+            if returned < PAGE_SIZE {
+                break;
+            }
+        }
+
+        let mut report = TriageReport {
+            dry_run: self.dry_run,
+            entries: vec![],
+        };
+        for invitation in invitations {
+            let (decision, reason) = self.decide(&invitation);
+            let action = match decision {
+                TriageDecision::Accept => Some(InvitationAction::Accept),
+                TriageDecision::Ignore => Some(InvitationAction::Ignore),
+                TriageDecision::Skip => None,
+            };
+
+            let mut error = None;
+            let mut rate_limited = false;
+            if let (Some(action), false) = (action, self.dry_run) {
+                if let Err(e) = inner
+                    .reply_invitation(&invitation.entity_urn, &invitation.shared_secret, action)
+                    .await
+                {
+                    rate_limited = matches!(e, LinkedinError::RateLimit);
+                    error = Some(e.to_string());
+                }
+            }
+
+            report.entries.push(TriageEntry {
+                invitation,
+                decision,
+                reason,
+                error,
+            });
+
+            // Stop rather than burn through the rest; a later run picks them up
+            if rate_limited {
+                break;
+            }
+        }
+
+        Ok(report)
+    }
+}
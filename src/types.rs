@@ -5,10 +5,12 @@ use isolang;
 use my_country::Country;
 use phonenumber::PhoneNumber;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use serde_json::Value;
-use time::Month;
+use serde_json::{json, Value};
+use time::{Month, OffsetDateTime};
 use url::Url;
 
+use crate::LinkedinError;
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Locale {
     pub country: Country,
@@ -76,7 +78,7 @@ impl Address {
         let parts: Vec<&str> = raw_address.split(',').map(|s| s.trim()).collect();
         Self {
             raw: raw_address.to_string(),
-            street: parts.get(0).map(|s| s.to_string()),
+            street: parts.first().map(|s| s.to_string()),
             city: parts.get(1).map(|s| s.to_string()),
             state: parts.get(2).map(|s| s.to_string()),
             country: None,     // Cannot be derived from a simple string parse
@@ -181,7 +183,7 @@ impl Profile {
     pub fn get_profile_id(&self) -> Option<String> {
         self.entity_urn
             .as_ref()
-            .and_then(|urn| urn.split(':').last().map(|id| id.to_string()))
+            .and_then(|urn| urn.split(':').next_back().map(|id| id.to_string()))
     }
 }
 
@@ -224,7 +226,6 @@ impl BirthDate {
     /// Get as a proper date if all fields are present
     pub fn as_date(&self) -> Option<time::Date> {
         if let (Some(year), Some(month), Some(day)) = (self.year, self.month, self.day) {
-            let month = Month::try_from(month).ok()?;
             time::Date::from_calendar_date(year as i32, month, day).ok()
         } else {
             None
@@ -711,6 +712,16 @@ pub struct MiniProfile {
     pub picture: Option<VectorImageContainer>,
 }
 
+impl MiniProfile {
+    /// The profile URN, preferring the dash `fsd_profile` form.
+    pub fn urn(&self) -> Option<UniformResourceName> {
+        self.dash_entity_urn
+            .as_deref()
+            .or(self.entity_urn.as_deref())
+            .and_then(|urn| UniformResourceName::parse(urn).ok())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProfilePicture {
@@ -753,6 +764,18 @@ pub struct VectorImage {
     pub artifacts: Vec<ImageArtifact>,
 }
 
+impl VectorImage {
+    /// Get the URL of the widest artifact
+    pub fn largest_url(&self) -> Option<Url> {
+        let root_url = self.root_url.as_deref().unwrap_or("");
+        self.artifacts
+            .iter()
+            .max_by_key(|artifact| artifact.width)
+            .and_then(|artifact| artifact.file_identifying_url_path_segment.as_deref())
+            .and_then(|segment| Url::parse(&format!("{root_url}{segment}")).ok())
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ImageArtifact {
@@ -815,6 +838,109 @@ pub struct Connection {
     pub urn_id: String,
     pub public_id: String,
     pub distance: String,
+    pub name: Option<String>,
+    pub headline: Option<String>,
+}
+
+/// Connections the current user shares with another profile
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharedConnections {
+    /// Total shared connections as LinkedIn reports it, which can differ from
+    /// what search returns. `None` when the profile highlights don't say.
+    pub count: Option<u64>,
+    pub connections: Vec<Connection>,
+}
+
+impl SharedConnections {
+    /// Read the shared connection count from a `/identity/profiles/{id}/highlights` response.
+    pub fn from_highlights(value: &Value, connections: Vec<Connection>) -> Self {
+        let count = value
+            .get("elements")
+            .and_then(|e| e.as_array())
+            .into_iter()
+            .flatten()
+            .find_map(|highlight| {
+                highlight
+                    .get("sharedConnectionsInfo")
+                    .or_else(|| highlight.get("sharedConnections"))
+                    .and_then(|info| info.get("numberOfSharedConnections"))
+                    .and_then(|n| n.as_u64())
+            });
+
+        SharedConnections { count, connections }
+    }
+}
+
+/// A first-degree connection of the current user
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OwnConnection {
+    pub urn: UniformResourceName,
+    pub public_id: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub headline: Option<String>,
+    pub picture: Option<Url>,
+    #[serde(with = "time::serde::timestamp::milliseconds_i64::option", default)]
+    pub connected_at: Option<OffsetDateTime>,
+}
+
+impl OwnConnection {
+    /// Parse a dash connection element. Returns `None` without a member URN.
+    pub fn from_voyager(value: &Value) -> Option<Self> {
+        let member = value.get("connectedMemberResolutionResult");
+        let str_of = |key: &str| {
+            member
+                .and_then(|m| m.get(key))
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+        };
+        let urn = value
+            .get("connectedMember")
+            .and_then(|u| u.as_str())
+            .or_else(|| {
+                member
+                    .and_then(|m| m.get("entityUrn"))
+                    .and_then(|u| u.as_str())
+            })
+            .and_then(|u| UniformResourceName::parse(u).ok())?;
+
+        Some(OwnConnection {
+            urn,
+            public_id: str_of("publicIdentifier"),
+            first_name: str_of("firstName"),
+            last_name: str_of("lastName"),
+            headline: str_of("headline"),
+            picture: member
+                .and_then(|m| m.get("profilePicture"))
+                .and_then(|p| p.get("displayImageReference"))
+                .and_then(|r| r.get("vectorImage"))
+                .and_then(|v| serde_json::from_value::<VectorImage>(v.clone()).ok())
+                .and_then(|image| image.largest_url()),
+            connected_at: value
+                .get("createdAt")
+                .and_then(|t| t.as_i64())
+                .and_then(crate::utils::datetime_from_millis),
+        })
+    }
+}
+
+/// Order of the own-connections listing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConnectionSort {
+    #[default]
+    RecentlyAdded,
+    FirstName,
+    LastName,
+}
+
+impl ConnectionSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConnectionSort::RecentlyAdded => "RECENTLY_ADDED",
+            ConnectionSort::FirstName => "FIRSTNAME_LASTNAME",
+            ConnectionSort::LastName => "LASTNAME_FIRSTNAME",
+        }
+    }
 }
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MemberBadges {
@@ -828,22 +954,393 @@ pub struct PersonSearchResult {
     pub urn_id: String,
     pub public_id: String,
     pub distance: String,
+    pub name: Option<String>,
+    pub headline: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl From<PersonSearchResult> for Connection {
+    fn from(result: PersonSearchResult) -> Self {
+        Connection {
+            urn_id: result.urn_id,
+            public_id: result.public_id,
+            distance: result.distance,
+            name: result.name,
+            headline: result.headline,
+        }
+    }
+}
+
+/// A pending invitation received by the current user
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Invitation {
-    pub entity_urn: Option<String>,
+    pub entity_urn: UniformResourceName,
     pub shared_secret: String,
+    pub invitation_type: InvitationType,
+    /// The member who sent it, absent for page, event and newsletter invitations
+    pub sender: Option<MiniProfile>,
+    /// The note sent with the invitation
+    pub message: Option<String>,
+    #[serde(with = "time::serde::timestamp::milliseconds_i64::option", default)]
+    pub sent_at: Option<OffsetDateTime>,
+    /// Connections shared with the sender
+    pub shared_connections: u64,
+    /// The shared connections LinkedIn highlights, usually only a few
+    pub shared_connection_profiles: Vec<MiniProfile>,
+}
+
+/// What an invitation invites the current user to do
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvitationType {
+    #[default]
+    Connection,
+    Follow,
+    Event,
+    Page,
+    Newsletter,
+}
+
+const SHARED_CONNECTIONS_INSIGHT: &str =
+    "com.linkedin.voyager.relationships.shared.connection.SharedConnectionsInsight";
+
+impl Invitation {
+    /// Parse a received invitation view element. Returns `None` without an
+    /// `entityUrn` or `sharedSecret`.
+    pub fn from_voyager(value: &Value) -> Option<Self> {
+        let invitation = value.get("invitation").unwrap_or(value);
+        let entity_urn = UniformResourceName::parse(invitation.get("entityUrn")?.as_str()?).ok()?;
+        let str_of = |key: &str| invitation.get(key).and_then(|v| v.as_str());
+
+        let invitation_type = match str_of("genericInvitationType")
+            .or_else(|| str_of("invitationType"))
+            .unwrap_or_default()
+        {
+            "ORGANIZATION" | "PAGE" => InvitationType::Page,
+            "EVENT" => InvitationType::Event,
+            "CONTENT_SERIES" | "NEWSLETTER" => InvitationType::Newsletter,
+            "FOLLOW" | "MEMBER_FOLLOW" => InvitationType::Follow,
+            _ => InvitationType::Connection,
+        };
+
+        let shared = value
+            .get("insights")
+            .and_then(|i| i.as_array())
+            .into_iter()
+            .flatten()
+            .find_map(|insight| {
+                insight
+                    .get("sharedInsight")
+                    .unwrap_or(insight)
+                    .get(SHARED_CONNECTIONS_INSIGHT)
+            });
+
+        Some(Invitation {
+            entity_urn,
+            shared_secret: str_of("sharedSecret")?.to_string(),
+            invitation_type,
+            sender: invitation
+                .get("fromMember")
+                .and_then(|m| serde_json::from_value(m.clone()).ok()),
+            message: str_of("message")
+                .filter(|m| !m.is_empty())
+                .map(|s| s.to_string()),
+            sent_at: invitation
+                .get("sentTime")
+                .and_then(|t| t.as_i64())
+                .and_then(crate::utils::datetime_from_millis),
+            shared_connections: shared
+                .and_then(|s| s.get("totalCount"))
+                .and_then(|c| c.as_u64())
+                .unwrap_or(0),
+            shared_connection_profiles: shared
+                .and_then(|s| s.get("connections"))
+                .and_then(|c| c.as_array())
+                .map(|connections| {
+                    connections
+                        .iter()
+                        .filter_map(|c| serde_json::from_value(c.clone()).ok())
+                        .collect()
+                })
+                .unwrap_or_default(),
+        })
+    }
+}
+
+/// A connection invitation sent by the current user
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SentInvitation {
+    pub entity_urn: UniformResourceName,
+    pub shared_secret: String,
+    pub recipient: Option<MiniProfile>,
+    #[serde(with = "time::serde::timestamp::milliseconds_i64::option", default)]
+    pub sent_at: Option<OffsetDateTime>,
+    /// The note sent with the invitation
+    pub message: Option<String>,
+}
+
+impl SentInvitation {
+    /// Parse a sent invitation view element. Returns `None` without an
+    /// `entityUrn` or `sharedSecret`.
+    pub fn from_voyager(value: &Value) -> Option<Self> {
+        let invitation = value.get("invitation").unwrap_or(value);
+        let entity_urn = UniformResourceName::parse(invitation.get("entityUrn")?.as_str()?).ok()?;
+
+        Some(SentInvitation {
+            entity_urn,
+            shared_secret: invitation.get("sharedSecret")?.as_str()?.to_string(),
+            recipient: invitation
+                .get("toMember")
+                .and_then(|m| serde_json::from_value(m.clone()).ok()),
+            sent_at: invitation
+                .get("sentTime")
+                .and_then(|t| t.as_i64())
+                .and_then(crate::utils::datetime_from_millis),
+            message: invitation
+                .get("message")
+                .and_then(|m| m.as_str())
+                .filter(|m| !m.is_empty())
+                .map(|s| s.to_string()),
+        })
+    }
+}
+
+/// A reply to an invitation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvitationAction {
+    Accept,
+    Ignore,
+    /// Withdraw an invitation the current user sent
+    Withdraw,
 }
 
+impl InvitationAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InvitationAction::Accept => "accept",
+            InvitationAction::Ignore => "ignore",
+            InvitationAction::Withdraw => "withdraw",
+        }
+    }
+}
+
+/// Longest note LinkedIn accepts on a connection invitation
+pub const MAX_INVITATION_NOTE_LENGTH: usize = 300;
+
+/// Outcome of sending a connection invitation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvitationResult {
+    Sent,
+    AlreadyPending,
+    AlreadyConnected,
+    /// The member only accepts invitations from people who know their email
+    EmailRequired,
+    WeeklyLimitReached,
+}
+
+impl InvitationResult {
+    /// Map a Voyager error code from a rejected invitation.
+    pub fn from_error_code(code: &str) -> Option<Self> {
+        match code {
+            "CANT_RESEND_YET" | "INVITATION_ALREADY_EXISTS" | "DUPLICATE_INVITATION" => {
+                Some(InvitationResult::AlreadyPending)
+            }
+            "ALREADY_CONNECTED" | "ALREADY_FIRST_DEGREE_CONNECTION" => {
+                Some(InvitationResult::AlreadyConnected)
+            }
+            "EMAIL_REQUIRED" | "REQUIRES_EMAIL" | "MISSING_EMAIL" => {
+                Some(InvitationResult::EmailRequired)
+            }
+            "FUSE_LIMIT_EXCEEDED" | "WEEKLY_INVITATION_LIMIT_REACHED" => {
+                Some(InvitationResult::WeeklyLimitReached)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Kind of entity the current user can follow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FollowKind {
+    Member,
+    Company,
+    School,
+    Hashtag,
+}
+
+impl FollowKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FollowKind::Member => "MEMBER",
+            FollowKind::Company => "COMPANY",
+            FollowKind::School => "SCHOOL",
+            FollowKind::Hashtag => "HASHTAG",
+        }
+    }
+
+    /// Build the URN of an entity of this kind, e.g. `urn:li:fsd_company:1441`
+    pub fn urn(&self, id: &str) -> UniformResourceName {
+        let namespace = match self {
+            FollowKind::Member => "fsd_profile",
+            FollowKind::Company => "fsd_company",
+            FollowKind::School => "fsd_school",
+            FollowKind::Hashtag => "hashtag",
+        };
+        UniformResourceName {
+            namespace: namespace.to_string(),
+            id: id.to_string(),
+        }
+    }
+
+    /// Classify a URN by its namespace. Returns `None` for entities that
+    /// can't be followed.
+    pub fn from_urn(urn: &UniformResourceName) -> Option<Self> {
+        match urn.namespace.as_str() {
+            "fsd_profile" | "fs_miniProfile" | "fs_profile" | "member" => Some(FollowKind::Member),
+            "fsd_company"
+            | "fs_miniCompany"
+            | "fs_normalized_company"
+            | "company"
+            | "organization" => Some(FollowKind::Company),
+            "fsd_school" | "fs_miniSchool" | "fs_school" | "school" => Some(FollowKind::School),
+            "hashtag" | "fs_hashtag" | "fsd_hashtag" => Some(FollowKind::Hashtag),
+            _ => None,
+        }
+    }
+}
+
+/// An entity the current user follows
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FollowedEntity {
+    pub urn: UniformResourceName,
+    pub kind: FollowKind,
+    /// Member, company or school name, or the hashtag without `#`
+    pub name: Option<String>,
+    pub followers_count: Option<u64>,
+}
+
+impl FollowedEntity {
+    /// Parse a `followedEntities` element, whose `entity` is a union keyed by
+    /// the mini profile, company or school type. Returns `None` without a
+    /// followable entity URN.
+    pub fn from_voyager(value: &Value) -> Option<Self> {
+        let entity = value.get("entity").unwrap_or(value);
+        let entity = match entity.as_object() {
+            Some(union) if union.len() == 1 && !union.contains_key("entityUrn") => {
+                union.values().next()?
+            }
+            _ => entity,
+        };
+        let urn = UniformResourceName::parse(
+            entity
+                .get("entityUrn")
+                .or_else(|| entity.get("objectUrn"))?
+                .as_str()?,
+        )
+        .ok()?;
+        let kind = FollowKind::from_urn(&urn)?;
+        let str_of = |key: &str| entity.get(key).and_then(|v| v.as_str());
+
+        let name = match kind {
+            FollowKind::Member => {
+                let name = [str_of("firstName"), str_of("lastName")]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                Some(name).filter(|n| !n.is_empty())
+            }
+            FollowKind::Hashtag => str_of("name")
+                .or_else(|| str_of("displayName"))
+                .map(|n| n.trim_start_matches('#').to_string())
+                .or_else(|| Some(urn.id.clone())),
+            _ => str_of("name").map(str::to_string),
+        };
+
+        let followers_count = value
+            .get("followingInfo")
+            .or_else(|| entity.get("followingInfo"))
+            .and_then(|f| f.get("followerCount"))
+            .and_then(|c| c.as_u64());
+
+        Some(FollowedEntity {
+            urn,
+            kind,
+            name,
+            followers_count,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct UniformResourceName {
     pub namespace: String, // the context of the id
     pub id: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Network distance between the current user and a profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkDistance {
+    /// The current user's own profile
+    Own,
+    First,
+    Second,
+    Third,
+    OutOfNetwork,
+}
+
+impl NetworkDistance {
+    pub fn from_voyager(value: &str) -> Option<Self> {
+        match value {
+            "SELF" => Some(NetworkDistance::Own),
+            "DISTANCE_1" => Some(NetworkDistance::First),
+            "DISTANCE_2" => Some(NetworkDistance::Second),
+            "DISTANCE_3" => Some(NetworkDistance::Third),
+            "OUT_OF_NETWORK" => Some(NetworkDistance::OutOfNetwork),
+            _ => None,
+        }
+    }
+}
+
+/// A profile's place in the current user's network, from `/networkinfo`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct NetworkInfo {
+    pub entity_urn: Option<UniformResourceName>,
     pub followers_count: u64,
+    pub connections_count: Option<u64>,
+    pub distance: Option<NetworkDistance>,
+    /// Whether the current user follows the profile
+    pub following: Option<bool>,
+    /// Whether the profile can be followed at all
+    pub followable: Option<bool>,
+}
+
+impl NetworkInfo {
+    /// Parse a `/networkinfo` response. Returns `None` without a
+    /// `followersCount`; other fields are `None` when absent.
+    pub fn from_voyager(value: &Value) -> Option<Self> {
+        let data = value.get("data").unwrap_or(value);
+        let count_of = |key: &str| data.get(key).and_then(|c| c.as_u64());
+        let flag = |key: &str| data.get(key).and_then(|f| f.as_bool());
+
+        Some(NetworkInfo {
+            entity_urn: data
+                .get("entityUrn")
+                .and_then(|u| u.as_str())
+                .and_then(|u| UniformResourceName::parse(u).ok()),
+            followers_count: count_of("followersCount")?,
+            connections_count: count_of("connectionsCount"),
+            distance: data
+                .get("distance")
+                .and_then(|d| d.get("value").unwrap_or(d).as_str())
+                .and_then(NetworkDistance::from_voyager),
+            following: flag("following"),
+            followable: flag("followable"),
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -856,14 +1353,1564 @@ pub struct Company {
     pub name: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// A conversation as listed in the messaging inbox
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Conversation {
     pub id: String,
+    pub entity_urn: Option<UniformResourceName>,
+    pub name: Option<String>,
+    pub conversation_type: ConversationType,
+    pub participants: Vec<MiniProfile>,
+    pub unread_count: u64,
+    pub read: bool,
+    #[serde(with = "time::serde::timestamp::milliseconds_i64::option", default)]
+    pub last_activity_at: Option<OffsetDateTime>,
+    pub last_message: Option<String>,
+    pub muted: bool,
+    pub archived: bool,
+    pub starred: bool,
+}
+
+/// Conversation with a given profile, as returned by `get_conversation_details`
+pub type ConversationDetails = Conversation;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConversationType {
+    #[default]
+    Member,
+    Group,
+    InMail,
+    Sponsored,
+}
+
+const MESSAGING_MEMBER: &str = "com.linkedin.voyager.messaging.MessagingMember";
+const MESSAGE_EVENT: &str = "com.linkedin.voyager.messaging.event.MessageEvent";
+
+impl Conversation {
+    /// Parse a `LEGACY_INBOX` conversation element. Returns `None` without an `entityUrn`.
+    pub fn from_voyager(value: &Value) -> Option<Self> {
+        let entity_urn = UniformResourceName::parse(value.get("entityUrn")?.as_str()?).ok()?;
+        let flag = |key: &str| value.get(key).and_then(|f| f.as_bool()).unwrap_or(false);
+        let last_event = value.get("events").and_then(|e| e.get(0));
+
+        let event_subtype = last_event
+            .and_then(|e| e.get("subtype"))
+            .and_then(|s| s.as_str())
+            .unwrap_or_default();
+        let conversation_type = if flag("groupChat") {
+            ConversationType::Group
+        } else if event_subtype.starts_with("SPONSORED") || flag("sponsored") {
+            ConversationType::Sponsored
+        } else if event_subtype == "INMAIL" || event_subtype == "INMAIL_REPLY" {
+            ConversationType::InMail
+        } else {
+            ConversationType::Member
+        };
+
+        Some(Conversation {
+            id: entity_urn.id.clone(),
+            entity_urn: Some(entity_urn),
+            name: value
+                .get("name")
+                .and_then(|n| n.as_str())
+                .filter(|n| !n.is_empty())
+                .map(|s| s.to_string()),
+            conversation_type,
+            participants: value
+                .get("participants")
+                .and_then(|p| p.as_array())
+                .map(|participants| participants.iter().filter_map(messaging_member).collect())
+                .unwrap_or_default(),
+            unread_count: value
+                .get("unreadCount")
+                .and_then(|c| c.as_u64())
+                .unwrap_or(0),
+            read: flag("read"),
+            last_activity_at: value
+                .get("lastActivityAt")
+                .and_then(|t| t.as_i64())
+                .and_then(crate::utils::datetime_from_millis),
+            last_message: last_event
+                .and_then(|e| e.get("eventContent"))
+                .and_then(|c| c.get(MESSAGE_EVENT))
+                .and_then(|m| {
+                    m.get("attributedBody")
+                        .and_then(|b| b.get("text"))
+                        .or_else(|| m.get("body"))
+                })
+                .and_then(|t| t.as_str())
+                .map(|s| s.to_string()),
+            muted: flag("muted")
+                || value.get("notificationStatus").and_then(|n| n.as_str()) == Some("MUTE"),
+            archived: flag("archived"),
+            starred: flag("starred"),
+        })
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ConversationDetails {
-    pub id: String,
+/// An event in a conversation's history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Message {
+    pub entity_urn: Option<UniformResourceName>,
+    pub sender: Option<MiniProfile>,
+    #[serde(with = "time::serde::timestamp::milliseconds_i64::option", default)]
+    pub created_at: Option<OffsetDateTime>,
+    pub subtype: Option<String>,
+    pub content: MessageContent,
+    pub reactions: Vec<MessageReaction>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MessageContent {
+    Message {
+        body: AttributedText,
+        attachments: Vec<MessageAttachment>,
+        media: Vec<MessageMedia>,
+    },
+    ParticipantChange {
+        added: Vec<MiniProfile>,
+        removed: Vec<MiniProfile>,
+    },
+    /// Any other system event, named by its Voyager type
+    Other { kind: String },
+}
+
+/// A file attached to a message
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageAttachment {
+    pub name: Option<String>,
+    pub media_type: Option<String>,
+    pub byte_size: Option<u64>,
+    pub url: Option<Url>,
+}
+
+/// An image, audio clip or other inline media in a message
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageMedia {
+    pub media_type: Option<String>,
+    pub url: Option<Url>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageReaction {
+    pub emoji: String,
+    pub count: u64,
+    pub viewer_reacted: bool,
+}
+
+/// A message accepted by LinkedIn
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SentMessage {
+    pub event_urn: UniformResourceName,
+    pub conversation_urn: UniformResourceName,
+    #[serde(with = "time::serde::timestamp::milliseconds_i64::option", default)]
+    pub created_at: Option<OffsetDateTime>,
+}
+
+/// When a participant last saw a conversation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeenReceipt {
+    pub reader: Option<UniformResourceName>,
+    /// The last message event the reader has seen
+    pub event_urn: Option<UniformResourceName>,
+    #[serde(with = "time::serde::timestamp::milliseconds_i64::option", default)]
+    pub seen_at: Option<OffsetDateTime>,
+}
+
+impl SeenReceipt {
+    /// Parse a seen-receipt element, as returned by the receipts endpoint and
+    /// pushed on the realtime stream.
+    pub fn from_voyager(value: &Value) -> Self {
+        let receipt = value.get("seenReceipt").unwrap_or(value);
+        let urn = |value: &Value, key: &str| {
+            value
+                .get(key)
+                .and_then(|u| u.as_str())
+                .and_then(|u| UniformResourceName::parse(u).ok())
+        };
+
+        SeenReceipt {
+            reader: urn(value, "fromEntity"),
+            event_urn: urn(receipt, "eventUrn"),
+            seen_at: receipt
+                .get("seenAt")
+                .and_then(|t| t.as_i64())
+                .and_then(crate::utils::datetime_from_millis),
+        }
+    }
+}
+
+/// The conversation a message event belongs to. Event ids look like
+/// "(<conversation id>,<message id>)".
+pub(crate) fn event_conversation_urn(event: &UniformResourceName) -> Option<UniformResourceName> {
+    let (conversation, _) = event.id.strip_prefix('(')?.split_once(',')?;
+    Some(UniformResourceName {
+        namespace: "fs_conversation".into(),
+        id: conversation.to_string(),
+    })
+}
+
+/// A write action on a single conversation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum ConversationAction {
+    MarkRead,
+    MarkUnread,
+    Archive,
+    Unarchive,
+    Mute,
+    Unmute,
+    Star,
+    Unstar,
+    /// Rename a group conversation
+    Rename(String),
+    /// Add profiles to a group conversation
+    AddParticipants(Vec<UniformResourceName>),
+    /// Remove profiles from a group conversation
+    RemoveParticipants(Vec<UniformResourceName>),
+    /// Leave a group conversation
+    Leave,
+    Delete,
+}
+
+impl ConversationAction {
+    /// The `$set` body for actions applied through a conversation patch.
+    pub(crate) fn patch(&self) -> Option<Value> {
+        let set = match self {
+            ConversationAction::MarkRead => json!({ "read": true }),
+            ConversationAction::MarkUnread => json!({ "read": false }),
+            ConversationAction::Archive => json!({ "archived": true }),
+            ConversationAction::Unarchive => json!({ "archived": false }),
+            ConversationAction::Mute => json!({ "muted": true }),
+            ConversationAction::Unmute => json!({ "muted": false }),
+            ConversationAction::Star => json!({ "starred": true }),
+            ConversationAction::Unstar => json!({ "starred": false }),
+            ConversationAction::Rename(name) => json!({ "name": name }),
+            ConversationAction::AddParticipants(_)
+            | ConversationAction::RemoveParticipants(_)
+            | ConversationAction::Leave
+            | ConversationAction::Delete => return None,
+        };
+        Some(json!({ "patch": { "$set": set } }))
+    }
+}
+
+/// A conversation action accepted by LinkedIn
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConversationUpdate {
+    pub conversation_urn: UniformResourceName,
+    pub action: ConversationAction,
+    /// The updated conversation, when LinkedIn returns it
+    pub conversation: Option<Conversation>,
+}
+
+impl ConversationUpdate {
+    /// Parse the response to `action` on `conversation_urn`. The response
+    /// may be empty, wrap the conversation in `value`, or be the conversation.
+    pub fn from_voyager(
+        conversation_urn: UniformResourceName,
+        action: ConversationAction,
+        value: &Value,
+    ) -> Self {
+        let conversation = Conversation::from_voyager(value.get("value").unwrap_or(value));
+        ConversationUpdate {
+            conversation_urn: conversation
+                .as_ref()
+                .and_then(|c| c.entity_urn.clone())
+                .unwrap_or(conversation_urn),
+            action,
+            conversation,
+        }
+    }
+}
+
+const PARTICIPANT_CHANGE_EVENT: &str =
+    "com.linkedin.voyager.messaging.event.ParticipantChangeEvent";
+
+impl Message {
+    /// Parse a `/messaging/conversations/{id}/events` element.
+    pub fn from_voyager(value: &Value) -> Self {
+        let event_content = value.get("eventContent");
+
+        let content = if let Some(message) = event_content.and_then(|c| c.get(MESSAGE_EVENT)) {
+            let mut body = message
+                .get("attributedBody")
+                .map(AttributedText::from_voyager)
+                .unwrap_or_default();
+            if body.text.is_empty() {
+                body.text = message
+                    .get("body")
+                    .and_then(|b| b.as_str())
+                    .unwrap_or_default()
+                    .to_string();
+            }
+
+            MessageContent::Message {
+                body,
+                attachments: message
+                    .get("attachments")
+                    .and_then(|a| a.as_array())
+                    .map(|attachments| {
+                        attachments
+                            .iter()
+                            .map(|attachment| MessageAttachment {
+                                name: attachment
+                                    .get("name")
+                                    .and_then(|n| n.as_str())
+                                    .map(|s| s.to_string()),
+                                media_type: attachment
+                                    .get("mediaType")
+                                    .and_then(|m| m.as_str())
+                                    .map(|s| s.to_string()),
+                                byte_size: attachment.get("byteSize").and_then(|b| b.as_u64()),
+                                url: attachment
+                                    .get("reference")
+                                    .and_then(|r| r.get("string"))
+                                    .and_then(|u| u.as_str())
+                                    .and_then(|u| Url::parse(u).ok()),
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                media: message
+                    .get("mediaAttachments")
+                    .and_then(|m| m.as_array())
+                    .map(|media| {
+                        media
+                            .iter()
+                            .map(|item| MessageMedia {
+                                media_type: item
+                                    .get("mediaType")
+                                    .and_then(|m| m.as_str())
+                                    .map(|s| s.to_string()),
+                                url: item
+                                    .get("audioMetadata")
+                                    .and_then(|a| a.get("url"))
+                                    .or_else(|| item.get("url"))
+                                    .and_then(|u| u.as_str())
+                                    .and_then(|u| Url::parse(u).ok())
+                                    .or_else(|| {
+                                        item.get("vectorImage")
+                                            .and_then(|v| {
+                                                serde_json::from_value::<VectorImage>(v.clone())
+                                                    .ok()
+                                            })
+                                            .and_then(|image| image.largest_url())
+                                    }),
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+            }
+        } else if let Some(change) = event_content.and_then(|c| c.get(PARTICIPANT_CHANGE_EVENT)) {
+            let members = |key: &str| {
+                change
+                    .get(key)
+                    .and_then(|p| p.as_array())
+                    .map(|members| members.iter().filter_map(messaging_member).collect())
+                    .unwrap_or_default()
+            };
+            MessageContent::ParticipantChange {
+                added: members("addedParticipants"),
+                removed: members("removedParticipants"),
+            }
+        } else {
+            MessageContent::Other {
+                kind: event_content
+                    .and_then(|c| c.as_object())
+                    .and_then(|c| c.keys().next())
+                    .map(|k| k.rsplit('.').next().unwrap_or(k).to_string())
+                    .unwrap_or_default(),
+            }
+        };
+
+        Message {
+            entity_urn: value
+                .get("entityUrn")
+                .and_then(|u| u.as_str())
+                .and_then(|u| UniformResourceName::parse(u).ok()),
+            sender: value.get("from").and_then(messaging_member),
+            created_at: value
+                .get("createdAt")
+                .and_then(|t| t.as_i64())
+                .and_then(crate::utils::datetime_from_millis),
+            subtype: value
+                .get("subtype")
+                .and_then(|s| s.as_str())
+                .map(|s| s.to_string()),
+            content,
+            reactions: value
+                .get("reactionSummaries")
+                .and_then(|r| r.as_array())
+                .map(|summaries| {
+                    summaries
+                        .iter()
+                        .filter_map(|summary| {
+                            Some(MessageReaction {
+                                emoji: summary.get("emoji")?.as_str()?.to_string(),
+                                count: summary.get("count").and_then(|c| c.as_u64()).unwrap_or(0),
+                                viewer_reacted: summary
+                                    .get("viewerReacted")
+                                    .and_then(|v| v.as_bool())
+                                    .unwrap_or(false),
+                            })
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// The message text, if this is a message rather than a system event
+    pub fn text(&self) -> Option<&str> {
+        match &self.content {
+            MessageContent::Message { body, .. } => Some(&body.text),
+            _ => None,
+        }
+    }
+}
+
+/// Read the mini profile out of a `MessagingMember` union
+pub(crate) fn messaging_member(value: &Value) -> Option<MiniProfile> {
+    let member = value.get(MESSAGING_MEMBER).unwrap_or(value);
+    serde_json::from_value(member.get("miniProfile")?.clone()).ok()
+}
+
+/// A job posting
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct JobPosting {
+    pub job_posting_id: String,
+    pub entity_urn: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub company_name: Option<String>,
+    pub formatted_location: Option<String>,
+    #[serde(with = "time::serde::timestamp::milliseconds_i64::option", default)]
+    pub listed_at: Option<OffsetDateTime>,
+    pub skills: Vec<String>,
+}
+
+// Weight of each signal when scoring a profile against a job posting
+const SKILL_WEIGHT: f64 = 0.6;
+const TITLE_WEIGHT: f64 = 0.25;
+const LOCATION_WEIGHT: f64 = 0.15;
+
+impl JobPosting {
+    /// Score a profile against this posting using skills, position titles and locations.
+    ///
+    /// The score is between 0.0 and 1.0. When the posting lists no skills, the
+    /// skill component is left out and the remaining weights are rescaled.
+    pub fn score_profile(&self, profile: &ProfileView) -> JobMatch {
+        let profile_skills: Vec<String> = profile
+            .skills
+            .iter()
+            .chain(profile.skill_view.elements.iter())
+            .map(|skill| normalize(&skill.name))
+            .collect();
+
+        let (matched_skills, missing_skills): (Vec<String>, Vec<String>) = self
+            .skills
+            .iter()
+            .cloned()
+            .partition(|skill| profile_skills.contains(&normalize(skill)));
+
+        let title_match = self.title.as_deref().is_some_and(|job_title| {
+            let job_words = significant_words(job_title);
+            profile
+                .position_view
+                .elements
+                .iter()
+                .filter_map(|position| position.title.as_deref())
+                .chain(profile.profile.headline.as_deref())
+                .any(|title| {
+                    significant_words(title)
+                        .iter()
+                        .any(|word| job_words.contains(word))
+                })
+        });
+
+        let location_match = self
+            .formatted_location
+            .as_deref()
+            .is_some_and(|job_location| {
+                let job_location = normalize(job_location);
+                profile
+                    .position_view
+                    .elements
+                    .iter()
+                    .filter_map(|position| position.location_name.as_deref())
+                    .chain(profile.profile.location_name.as_deref())
+                    .chain(profile.profile.geo_location_name.as_deref())
+                    .filter_map(|location| location.split(',').next())
+                    .map(normalize)
+                    .any(|city| !city.is_empty() && job_location.contains(&city))
+            });
+
+        let mut score = 0.0;
+        let mut total_weight = TITLE_WEIGHT + LOCATION_WEIGHT;
+        if !self.skills.is_empty() {
+            score += SKILL_WEIGHT * matched_skills.len() as f64 / self.skills.len() as f64;
+            total_weight += SKILL_WEIGHT;
+        }
+        if title_match {
+            score += TITLE_WEIGHT;
+        }
+        if location_match {
+            score += LOCATION_WEIGHT;
+        }
+
+        JobMatch {
+            score: score / total_weight,
+            matched_skills,
+            missing_skills,
+            title_match,
+            location_match,
+        }
+    }
+}
+
+fn normalize(text: &str) -> String {
+    text.trim().to_lowercase()
+}
+
+fn significant_words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.len() > 2)
+        .map(normalize)
+        .collect()
+}
+
+/// Result of scoring a profile against a job posting
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobMatch {
+    pub score: f64,
+    pub matched_skills: Vec<String>,
+    pub missing_skills: Vec<String>,
+    pub title_match: bool,
+    pub location_match: bool,
+}
+
+/// Whether a skill required by a job posting is on the member's profile
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobSkillMatch {
+    pub skill: String,
+    pub on_profile: bool,
+}
+
+/// Applicant insights for a job posting (premium only)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct JobApplicantInsights {
+    pub applicant_count: Option<u64>,
+    pub applicants_in_past_day: Option<u64>,
+    pub seniority_distribution: Vec<InsightBucket>,
+    pub education_distribution: Vec<InsightBucket>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InsightBucket {
+    pub label: String,
+    pub percentage: f64,
+}
+
+/// A typed update from a company, profile or home feed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FeedUpdate {
+    TextPost {
+        post: FeedPost,
+    },
+    ArticleShare {
+        post: FeedPost,
+        article: FeedArticle,
+    },
+    Reshare {
+        post: FeedPost,
+        original: Option<Box<FeedUpdate>>,
+    },
+    Image {
+        post: FeedPost,
+        images: Vec<Url>,
+    },
+    Video {
+        post: FeedPost,
+        video: FeedVideo,
+    },
+    Document {
+        post: FeedPost,
+        document: FeedDocument,
+    },
+    Poll {
+        post: FeedPost,
+        poll: FeedPoll,
+    },
+    JobShare {
+        post: FeedPost,
+        job: FeedJob,
+    },
+    Celebration {
+        post: FeedPost,
+        celebration: FeedCelebration,
+    },
+}
+
+/// Fields shared by every kind of feed update
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct FeedPost {
+    pub urn: Option<UniformResourceName>,
+    pub actor: Option<FeedActor>,
+    pub commentary: Option<AttributedText>,
+    #[serde(with = "time::serde::timestamp::milliseconds_i64::option", default)]
+    pub published_at: Option<OffsetDateTime>,
+    pub social_counts: SocialCounts,
+}
+
+/// The member or company an update is attributed to
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct FeedActor {
+    pub urn: Option<UniformResourceName>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub image_url: Option<Url>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct SocialCounts {
+    pub likes: u64,
+    pub comments: u64,
+    pub reposts: u64,
+}
+
+/// Text with mention, hashtag and link spans
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct AttributedText {
+    pub text: String,
+    pub spans: Vec<TextSpan>,
+}
+
+/// A span of an `AttributedText`, counted in UTF-16 code units as LinkedIn does
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextSpan {
+    pub start: usize,
+    pub length: usize,
+    pub kind: SpanKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SpanKind {
+    ProfileMention {
+        urn: Option<UniformResourceName>,
+        public_id: Option<String>,
+    },
+    CompanyMention {
+        urn: Option<UniformResourceName>,
+    },
+    Hashtag {
+        tag: String,
+    },
+    Link {
+        url: Url,
+    },
+    Other {
+        name: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct FeedArticle {
+    pub url: Option<Url>,
+    pub title: Option<String>,
+    pub subtitle: Option<String>,
+    pub image_url: Option<Url>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct FeedVideo {
+    pub stream_url: Option<Url>,
+    pub thumbnail_url: Option<Url>,
+    pub duration_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct FeedDocument {
+    pub title: Option<String>,
+    pub url: Option<Url>,
+    pub page_count: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct FeedPoll {
+    pub question: Option<String>,
+    pub options: Vec<FeedPollOption>,
+    pub total_votes: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeedPollOption {
+    pub text: String,
+    pub votes: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct FeedJob {
+    pub job_posting_id: Option<String>,
+    pub title: Option<String>,
+    pub company_name: Option<String>,
+    pub url: Option<Url>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct FeedCelebration {
+    pub headline: Option<String>,
+    pub image_url: Option<Url>,
+}
+
+const UPDATE_V2: &str = "com.linkedin.voyager.feed.render.UpdateV2";
+const RENDER: &str = "com.linkedin.voyager.feed.render.";
+
+impl FeedUpdate {
+    /// Parse a `com.linkedin.voyager.feed.render.UpdateV2` element.
+    ///
+    /// Accepts the bare update or one wrapped in a `value` union. Updates
+    /// whose content isn't recognised are returned as a `TextPost`.
+    pub fn from_update_v2(value: &Value) -> Self {
+        let update = value
+            .get("value")
+            .and_then(|v| v.get(UPDATE_V2))
+            .unwrap_or(value);
+
+        let post = FeedPost::from_update_v2(update);
+
+        if let Some(reshared) = update.get("resharedUpdate") {
+            return FeedUpdate::Reshare {
+                post,
+                original: Some(Box::new(FeedUpdate::from_update_v2(reshared))),
+            };
+        }
+
+        let Some((component, content)) = update
+            .get("content")
+            .and_then(|c| c.as_object())
+            .and_then(|c| c.iter().next())
+        else {
+            return FeedUpdate::TextPost { post };
+        };
+
+        match component.trim_start_matches(RENDER) {
+            "ArticleComponent" => FeedUpdate::ArticleShare {
+                post,
+                article: FeedArticle {
+                    url: navigation_url(content),
+                    title: text_of(content, "title"),
+                    subtitle: text_of(content, "subtitle"),
+                    image_url: content
+                        .get("largeImage")
+                        .or_else(|| content.get("smallImage"))
+                        .and_then(image_url),
+                },
+            },
+            "ImageComponent" => FeedUpdate::Image {
+                post,
+                images: content
+                    .get("images")
+                    .and_then(|i| i.as_array())
+                    .map(|images| images.iter().filter_map(image_url).collect())
+                    .unwrap_or_default(),
+            },
+            "LinkedInVideoComponent" => {
+                let metadata = content.get("videoPlayMetadata").unwrap_or(content);
+                FeedUpdate::Video {
+                    post,
+                    video: FeedVideo {
+                        stream_url: metadata
+                            .get("progressiveStreams")
+                            .and_then(|s| s.as_array())
+                            .and_then(|streams| {
+                                streams.iter().max_by_key(|stream| {
+                                    stream.get("width").and_then(|w| w.as_u64())
+                                })
+                            })
+                            .and_then(|s| s.get("streamingLocations"))
+                            .and_then(|l| l.get(0))
+                            .and_then(|l| l.get("url"))
+                            .and_then(|u| u.as_str())
+                            .and_then(|u| Url::parse(u).ok()),
+                        thumbnail_url: metadata
+                            .get("thumbnail")
+                            .and_then(|t| serde_json::from_value::<VectorImage>(t.clone()).ok())
+                            .and_then(|image| image.largest_url()),
+                        duration_ms: metadata.get("duration").and_then(|d| d.as_u64()),
+                    },
+                }
+            }
+            "DocumentComponent" => {
+                let document = content.get("document").unwrap_or(content);
+                FeedUpdate::Document {
+                    post,
+                    document: FeedDocument {
+                        title: document
+                            .get("title")
+                            .and_then(|t| t.as_str())
+                            .map(|s| s.to_string()),
+                        url: document
+                            .get("transcribedDocumentUrl")
+                            .or_else(|| document.get("manifestUrl"))
+                            .and_then(|u| u.as_str())
+                            .and_then(|u| Url::parse(u).ok()),
+                        page_count: document
+                            .get("totalPageCount")
+                            .and_then(|c| c.as_u64())
+                            .map(|c| c as u32),
+                    },
+                }
+            }
+            "PollComponent" => {
+                let summary = content.get("pollSummary").unwrap_or(content);
+                FeedUpdate::Poll {
+                    post,
+                    poll: FeedPoll {
+                        question: text_of(summary, "question"),
+                        options: summary
+                            .get("pollOptionSummaries")
+                            .and_then(|o| o.as_array())
+                            .map(|options| {
+                                options
+                                    .iter()
+                                    .filter_map(|option| {
+                                        Some(FeedPollOption {
+                                            text: text_of(option.get("option")?, "text")?,
+                                            votes: option.get("voteCount").and_then(|v| v.as_u64()),
+                                        })
+                                    })
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                        total_votes: summary.get("uniqueVotersCount").and_then(|v| v.as_u64()),
+                    },
+                }
+            }
+            "EntityComponent"
+                if navigation_url(content).is_some_and(|url| url.path().starts_with("/jobs/")) =>
+            {
+                let url = navigation_url(content);
+                FeedUpdate::JobShare {
+                    post,
+                    job: FeedJob {
+                        job_posting_id: url
+                            .as_ref()
+                            .and_then(|u| u.path_segments())
+                            .and_then(|mut segments| segments.rfind(|s| !s.is_empty()))
+                            .map(|s| s.to_string()),
+                        title: text_of(content, "title"),
+                        company_name: text_of(content, "subtitle"),
+                        url,
+                    },
+                }
+            }
+            "CelebrationComponent" => FeedUpdate::Celebration {
+                post,
+                celebration: FeedCelebration {
+                    headline: text_of(content, "headline"),
+                    image_url: content.get("image").and_then(image_url),
+                },
+            },
+            _ => FeedUpdate::TextPost { post },
+        }
+    }
+
+    /// Fields shared by every kind of update
+    pub fn post(&self) -> &FeedPost {
+        match self {
+            FeedUpdate::TextPost { post }
+            | FeedUpdate::ArticleShare { post, .. }
+            | FeedUpdate::Reshare { post, .. }
+            | FeedUpdate::Image { post, .. }
+            | FeedUpdate::Video { post, .. }
+            | FeedUpdate::Document { post, .. }
+            | FeedUpdate::Poll { post, .. }
+            | FeedUpdate::JobShare { post, .. }
+            | FeedUpdate::Celebration { post, .. } => post,
+        }
+    }
+
+    /// URLs of all media attached to the update
+    pub fn media_urls(&self) -> Vec<Url> {
+        let urls: Vec<Option<&Url>> = match self {
+            FeedUpdate::TextPost { .. } | FeedUpdate::Reshare { .. } => vec![],
+            FeedUpdate::ArticleShare { article, .. } => {
+                vec![article.url.as_ref(), article.image_url.as_ref()]
+            }
+            FeedUpdate::Image { images, .. } => images.iter().map(Some).collect(),
+            FeedUpdate::Video { video, .. } => {
+                vec![video.stream_url.as_ref(), video.thumbnail_url.as_ref()]
+            }
+            FeedUpdate::Document { document, .. } => vec![document.url.as_ref()],
+            FeedUpdate::Poll { .. } => vec![],
+            FeedUpdate::JobShare { job, .. } => vec![job.url.as_ref()],
+            FeedUpdate::Celebration { celebration, .. } => vec![celebration.image_url.as_ref()],
+        };
+        urls.into_iter().flatten().cloned().collect()
+    }
+}
+
+impl FeedPost {
+    fn from_update_v2(update: &Value) -> Self {
+        let urn = update
+            .get("updateMetadata")
+            .and_then(|m| m.get("urn"))
+            .or_else(|| update.get("socialDetail").and_then(|s| s.get("urn")))
+            .and_then(|u| u.as_str())
+            .and_then(|u| UniformResourceName::parse(u).ok());
+
+        let actor = update.get("actor").map(|actor| FeedActor {
+            urn: actor
+                .get("urn")
+                .and_then(|u| u.as_str())
+                .and_then(|u| UniformResourceName::parse(u).ok()),
+            name: text_of(actor, "name"),
+            description: text_of(actor, "description"),
+            image_url: actor.get("image").and_then(image_url),
+        });
+
+        let counts = update
+            .get("socialDetail")
+            .and_then(|s| s.get("totalSocialActivityCounts"));
+        let count = |key: &str| {
+            counts
+                .and_then(|c| c.get(key))
+                .and_then(|n| n.as_u64())
+                .unwrap_or(0)
+        };
+
+        FeedPost {
+            published_at: urn.as_ref().and_then(activity_timestamp),
+            urn,
+            actor,
+            commentary: update
+                .get("commentary")
+                .and_then(|c| c.get("text"))
+                .map(AttributedText::from_voyager),
+            social_counts: SocialCounts {
+                likes: count("numLikes"),
+                comments: count("numComments"),
+                reposts: count("numShares"),
+            },
+        }
+    }
+}
+
+impl AttributedText {
+    /// Append text covered by a span
+    pub fn push_span(&mut self, text: &str, kind: SpanKind) {
+        self.spans.push(TextSpan {
+            start: self.text.encode_utf16().count(),
+            length: text.encode_utf16().count(),
+            kind,
+        });
+        self.text.push_str(text);
+    }
+
+    /// Parse a Voyager `TextViewModel`/`AttributedText` (`{ text, attributes }`).
+    pub fn from_voyager(value: &Value) -> Self {
+        let text = value
+            .get("text")
+            .and_then(|t| t.as_str())
+            .unwrap_or_default()
+            .to_string();
+
+        let spans = value
+            .get("attributes")
+            .and_then(|a| a.as_array())
+            .map(|attributes| {
+                attributes
+                    .iter()
+                    .filter_map(|attribute| TextSpan::from_voyager(attribute, &text))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self { text, spans }
+    }
+
+    /// The text covered by a span
+    pub fn span_text(&self, span: &TextSpan) -> String {
+        utf16_slice(&self.text, span.start, span.length)
+    }
+}
+
+fn utf16_slice(text: &str, start: usize, length: usize) -> String {
+    let units: Vec<u16> = text.encode_utf16().skip(start).take(length).collect();
+    String::from_utf16_lossy(&units)
+}
+
+impl TextSpan {
+    fn from_voyager(attribute: &Value, text: &str) -> Option<Self> {
+        let start = attribute.get("start")?.as_u64()? as usize;
+        let length = attribute.get("length")?.as_u64()? as usize;
+        let urn_of = |key: &str| {
+            attribute
+                .get(key)
+                .and_then(|e| e.get("entityUrn").or_else(|| e.get("objectUrn")))
+                .and_then(|u| u.as_str())
+                .and_then(|u| UniformResourceName::parse(u).ok())
+        };
+
+        // Messaging bodies use a `com.linkedin.pemberly.text.*` union instead of a string
+        if let Some((union_key, union)) = attribute
+            .get("type")?
+            .as_object()
+            .and_then(|t| t.iter().next())
+        {
+            let urn = union
+                .get("urn")
+                .and_then(|u| u.as_str())
+                .and_then(|u| UniformResourceName::parse(u).ok());
+            let kind = match union_key.rsplit('.').next().unwrap_or_default() {
+                "Entity" if urn.as_ref().is_some_and(|u| u.namespace.contains("ompany")) => {
+                    SpanKind::CompanyMention { urn }
+                }
+                "Entity" => SpanKind::ProfileMention {
+                    urn,
+                    public_id: None,
+                },
+                "Hyperlink" => SpanKind::Link {
+                    url: Url::parse(union.get("url")?.as_str()?).ok()?,
+                },
+                other => SpanKind::Other {
+                    name: other.to_string(),
+                },
+            };
+            return Some(Self {
+                start,
+                length,
+                kind,
+            });
+        }
+
+        let kind = match attribute.get("type")?.as_str()? {
+            "PROFILE_MENTION" | "PROFILE_FULLNAME" => SpanKind::ProfileMention {
+                urn: urn_of("miniProfile"),
+                public_id: attribute
+                    .get("miniProfile")
+                    .and_then(|p| p.get("publicIdentifier"))
+                    .and_then(|p| p.as_str())
+                    .map(|s| s.to_string()),
+            },
+            "COMPANY_NAME" => SpanKind::CompanyMention {
+                urn: urn_of("miniCompany"),
+            },
+            "HASHTAG" => SpanKind::Hashtag {
+                tag: utf16_slice(text, start, length)
+                    .trim_start_matches('#')
+                    .to_string(),
+            },
+            "HYPERLINK" => SpanKind::Link {
+                url: Url::parse(attribute.get("link")?.as_str()?).ok()?,
+            },
+            other => SpanKind::Other {
+                name: other.to_string(),
+            },
+        };
+
+        Some(Self {
+            start,
+            length,
+            kind,
+        })
+    }
+}
+
+/// A comment on a post, with any replies LinkedIn returned inline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Comment {
+    pub urn: Option<UniformResourceName>,
+    pub parent_urn: Option<UniformResourceName>,
+    pub commenter: Option<MiniProfile>,
+    pub text: AttributedText,
+    #[serde(with = "time::serde::timestamp::milliseconds_i64::option", default)]
+    pub created_at: Option<OffsetDateTime>,
+    pub likes: u64,
+    pub reply_count: u64,
+    pub replies: Vec<Comment>,
+}
+
+impl Comment {
+    /// Parse a `com.linkedin.voyager.feed.Comment` element, including inline replies.
+    pub fn from_voyager(value: &Value) -> Self {
+        let social_detail = value.get("socialDetail");
+        let count = |key: &str| {
+            social_detail
+                .and_then(|s| s.get("totalSocialActivityCounts"))
+                .and_then(|c| c.get(key))
+                .and_then(|n| n.as_u64())
+        };
+        let urn_of = |key: &str| {
+            value
+                .get(key)
+                .and_then(|u| u.as_str())
+                .and_then(|u| UniformResourceName::parse(u).ok())
+        };
+
+        let replies: Vec<Comment> = social_detail
+            .and_then(|s| s.get("comments"))
+            .and_then(|c| c.get("elements"))
+            .and_then(|e| e.as_array())
+            .map(|replies| replies.iter().map(Comment::from_voyager).collect())
+            .unwrap_or_default();
+
+        Comment {
+            urn: urn_of("urn").or_else(|| urn_of("entityUrn")),
+            parent_urn: urn_of("parentCommentUrn"),
+            commenter: value
+                .get("commenter")
+                .and_then(|c| c.get("com.linkedin.voyager.feed.MemberActor"))
+                .and_then(|m| m.get("miniProfile"))
+                .and_then(|p| serde_json::from_value(p.clone()).ok()),
+            text: value
+                .get("commentV2")
+                .map(AttributedText::from_voyager)
+                .unwrap_or_default(),
+            created_at: value
+                .get("createdTime")
+                .and_then(|t| t.as_i64())
+                .and_then(crate::utils::datetime_from_millis),
+            likes: count("numLikes").unwrap_or(0),
+            reply_count: count("numComments").unwrap_or(replies.len() as u64),
+            replies,
+        }
+    }
+}
+
+/// Read `{ key: { text } }`, the shape of Voyager text view models
+fn text_of(value: &Value, key: &str) -> Option<String> {
+    let field = value.get(key)?;
+    field
+        .get("text")
+        .unwrap_or(field)
+        .as_str()
+        .map(|s| s.to_string())
+}
+
+fn navigation_url(content: &Value) -> Option<Url> {
+    content
+        .get("navigationContext")
+        .and_then(|n| n.get("actionTarget"))
+        .and_then(|t| t.as_str())
+        .and_then(|t| Url::parse(t).ok())
+}
+
+/// Read the first vector image of a Voyager `ImageViewModel`
+fn image_url(image: &Value) -> Option<Url> {
+    image
+        .get("attributes")
+        .and_then(|a| a.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|attribute| attribute.get("vectorImage"))
+        .find_map(|vector| {
+            serde_json::from_value::<VectorImage>(vector.clone())
+                .ok()
+                .and_then(|image| image.largest_url())
+        })
+}
+
+/// Activity ids embed their creation time in milliseconds in the upper bits
+fn activity_timestamp(urn: &UniformResourceName) -> Option<OffsetDateTime> {
+    if urn.namespace != "activity" && urn.namespace != "share" && urn.namespace != "ugcPost" {
+        return None;
+    }
+    let id: u64 = urn.id.parse().ok()?;
+    crate::utils::datetime_from_millis((id >> 22) as i64)
+}
+
+/// The reactions LinkedIn offers on posts and comments. Serialized as the
+/// LinkedIn name returned by `as_str`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReactionType {
+    Like,
+    Celebrate,
+    Support,
+    Love,
+    Insightful,
+    Funny,
+}
+
+impl ReactionType {
+    pub const ALL: [ReactionType; 6] = [
+        ReactionType::Like,
+        ReactionType::Celebrate,
+        ReactionType::Support,
+        ReactionType::Love,
+        ReactionType::Insightful,
+        ReactionType::Funny,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReactionType::Like => "LIKE",
+            ReactionType::Celebrate => "PRAISE",
+            ReactionType::Support => "APPRECIATION",
+            ReactionType::Love => "EMPATHY",
+            ReactionType::Insightful => "INTEREST",
+            ReactionType::Funny => "ENTERTAINMENT",
+        }
+    }
+}
+
+impl FromStr for ReactionType {
+    type Err = LinkedinError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ReactionType::ALL
+            .into_iter()
+            .find(|reaction| reaction.as_str() == s)
+            .ok_or_else(|| LinkedinError::InvalidInput(format!("Unknown reaction type: {s}")))
+    }
+}
+
+impl Serialize for ReactionType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReactionType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        raw.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// A member's reaction to a post
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Reaction {
+    pub reaction_type: ReactionType,
+    pub actor_urn: Option<UniformResourceName>,
+    pub name: Option<String>,
+    pub headline: Option<String>,
+}
+
+/// Maximum length of a post's commentary, in characters
+pub const MAX_POST_LENGTH: usize = 3000;
+
+/// Who can see a new post
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PostVisibility {
+    #[default]
+    Public,
+    Connections,
+}
+
+/// Kind of media being uploaded, as named by the media-upload handshake
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaUploadType {
+    ImageSharing,
+    DocumentSharing,
+    MessagingPhotoAttachment,
+    MessagingFileAttachment,
+}
+
+impl MediaUploadType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MediaUploadType::ImageSharing => "IMAGE_SHARING",
+            MediaUploadType::DocumentSharing => "DOCUMENT_SHARING",
+            MediaUploadType::MessagingPhotoAttachment => "MESSAGING_PHOTO_ATTACHMENT",
+            MediaUploadType::MessagingFileAttachment => "MESSAGING_FILE_ATTACHMENT",
+        }
+    }
+}
+
+/// A file to send through the media-upload handshake
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediaFile {
+    pub filename: String,
+    pub bytes: Vec<u8>,
+}
+
+impl MediaFile {
+    pub fn new(filename: impl Into<String>, bytes: Vec<u8>) -> Self {
+        Self {
+            filename: filename.into(),
+            bytes,
+        }
+    }
+
+    /// Read a file from disk, keeping its file name
+    pub fn from_path(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Ok(Self::new(filename, std::fs::read(path)?))
+    }
+
+    /// MIME type guessed from the file extension
+    pub fn media_type(&self) -> &'static str {
+        let extension = self
+            .filename
+            .rsplit_once('.')
+            .map(|(_, ext)| ext.to_lowercase())
+            .unwrap_or_default();
+        match extension.as_str() {
+            "png" => "image/png",
+            "jpg" | "jpeg" => "image/jpeg",
+            "gif" => "image/gif",
+            "webp" => "image/webp",
+            "pdf" => "application/pdf",
+            "doc" => "application/msword",
+            "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+            "ppt" => "application/vnd.ms-powerpoint",
+            "pptx" => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+            "xls" => "application/vnd.ms-excel",
+            "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+            "txt" => "text/plain",
+            "csv" => "text/csv",
+            "zip" => "application/zip",
+            _ => "application/octet-stream",
+        }
+    }
+}
+
+/// Media attached to a new post
+#[derive(Debug, Clone, PartialEq)]
+pub enum PostMedia {
+    Image {
+        file: MediaFile,
+        alt_text: Option<String>,
+    },
+    Document {
+        file: MediaFile,
+        title: String,
+    },
+}
+
+/// A post ready to be published with `Linkedin::create_post`
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewPost {
+    pub commentary: AttributedText,
+    pub visibility: PostVisibility,
+    pub link: Option<Url>,
+    pub media: Option<PostMedia>,
+}
+
+impl NewPost {
+    pub fn builder() -> NewPostBuilder {
+        NewPostBuilder::default()
+    }
+}
+
+/// Builds a `NewPost`, keeping mention and hashtag spans in step with the text
+#[derive(Debug, Clone, Default)]
+pub struct NewPostBuilder {
+    commentary: AttributedText,
+    visibility: PostVisibility,
+    link: Option<Url>,
+    media: Option<PostMedia>,
+}
+
+impl NewPostBuilder {
+    /// Append plain text
+    pub fn text(mut self, text: &str) -> Self {
+        self.commentary.text.push_str(text);
+        self
+    }
+
+    /// Append a member's name as a mention
+    pub fn mention(mut self, name: &str, profile_urn: &UniformResourceName) -> Self {
+        self.commentary.push_span(
+            name,
+            SpanKind::ProfileMention {
+                urn: Some(profile_urn.clone()),
+                public_id: None,
+            },
+        );
+        self
+    }
+
+    /// Append a company's name as a mention
+    pub fn company_mention(mut self, name: &str, company_urn: &UniformResourceName) -> Self {
+        self.commentary.push_span(
+            name,
+            SpanKind::CompanyMention {
+                urn: Some(company_urn.clone()),
+            },
+        );
+        self
+    }
+
+    /// Append `#tag`
+    pub fn hashtag(mut self, tag: &str) -> Self {
+        let tag = tag.trim_start_matches('#');
+        self.commentary.push_span(
+            &format!("#{tag}"),
+            SpanKind::Hashtag {
+                tag: tag.to_string(),
+            },
+        );
+        self
+    }
+
+    pub fn visibility(mut self, visibility: PostVisibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    /// Attach a link preview
+    pub fn link(mut self, url: Url) -> Self {
+        self.link = Some(url);
+        self
+    }
+
+    pub fn image(mut self, file: MediaFile, alt_text: Option<String>) -> Self {
+        self.media = Some(PostMedia::Image { file, alt_text });
+        self
+    }
+
+    pub fn document(mut self, file: MediaFile, title: &str) -> Self {
+        self.media = Some(PostMedia::Document {
+            file,
+            title: title.to_string(),
+        });
+        self
+    }
+
+    pub fn build(self) -> Result<NewPost, LinkedinError> {
+        let length = self.commentary.text.chars().count();
+        if length == 0 && self.media.is_none() && self.link.is_none() {
+            return Err(LinkedinError::InvalidInput(
+                "A post needs text, a link or media".into(),
+            ));
+        }
+        if length > MAX_POST_LENGTH {
+            return Err(LinkedinError::InvalidInput(format!(
+                "Post is {length} characters, the limit is {MAX_POST_LENGTH}"
+            )));
+        }
+        if self.link.is_some() && self.media.is_some() {
+            return Err(LinkedinError::InvalidInput(
+                "A post can carry a link preview or media, not both".into(),
+            ));
+        }
+
+        Ok(NewPost {
+            commentary: self.commentary,
+            visibility: self.visibility,
+            link: self.link,
+            media: self.media,
+        })
+    }
+}
+
+/// Maximum length of a message body, in characters
+pub const MAX_MESSAGE_LENGTH: usize = 8000;
+/// Maximum size of a file attached to a message
+pub const MAX_MESSAGE_ATTACHMENT_BYTES: usize = 20 * 1024 * 1024;
+/// Maximum size of an image sent in a message
+pub const MAX_MESSAGE_IMAGE_BYTES: usize = 10 * 1024 * 1024;
+
+/// A message ready to be sent with `Linkedin::send_rich_message`
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewMessage {
+    pub body: AttributedText,
+    pub attachments: Vec<MediaFile>,
+    pub images: Vec<MediaFile>,
+}
+
+impl NewMessage {
+    pub fn builder() -> NewMessageBuilder {
+        NewMessageBuilder::default()
+    }
+}
+
+/// Builds a `NewMessage`, validating sizes before anything is uploaded
+#[derive(Debug, Clone, Default)]
+pub struct NewMessageBuilder {
+    body: AttributedText,
+    attachments: Vec<MediaFile>,
+    images: Vec<MediaFile>,
+}
+
+impl NewMessageBuilder {
+    /// Append plain text
+    pub fn text(mut self, text: &str) -> Self {
+        self.body.text.push_str(text);
+        self
+    }
+
+    /// Append a participant's name as a mention
+    pub fn mention(mut self, name: &str, profile_urn: &UniformResourceName) -> Self {
+        self.body.push_span(
+            name,
+            SpanKind::ProfileMention {
+                urn: Some(profile_urn.clone()),
+                public_id: None,
+            },
+        );
+        self
+    }
+
+    /// Append a link, which LinkedIn renders with a preview
+    pub fn link(mut self, url: Url) -> Self {
+        self.body
+            .push_span(url.as_str(), SpanKind::Link { url: url.clone() });
+        self
+    }
+
+    pub fn attachment(mut self, file: MediaFile) -> Self {
+        self.attachments.push(file);
+        self
+    }
+
+    pub fn image(mut self, file: MediaFile) -> Self {
+        self.images.push(file);
+        self
+    }
+
+    pub fn build(self) -> Result<NewMessage, LinkedinError> {
+        let length = self.body.text.chars().count();
+        if self.body.text.trim().is_empty() && self.attachments.is_empty() && self.images.is_empty()
+        {
+            return Err(LinkedinError::InvalidInput(
+                "A message needs text or an attachment".into(),
+            ));
+        }
+        if length > MAX_MESSAGE_LENGTH {
+            return Err(LinkedinError::InvalidInput(format!(
+                "Message is {length} characters, the limit is {MAX_MESSAGE_LENGTH}"
+            )));
+        }
+        for (files, limit) in [
+            (&self.attachments, MAX_MESSAGE_ATTACHMENT_BYTES),
+            (&self.images, MAX_MESSAGE_IMAGE_BYTES),
+        ] {
+            if let Some(file) = files.iter().find(|file| file.bytes.len() > limit) {
+                return Err(LinkedinError::InvalidInput(format!(
+                    "{} is {} bytes, the limit is {limit}",
+                    file.filename,
+                    file.bytes.len()
+                )));
+            }
+        }
+        if let Some(file) = self
+            .images
+            .iter()
+            .find(|file| !file.media_type().starts_with("image/"))
+        {
+            return Err(LinkedinError::InvalidInput(format!(
+                "{} is not an image",
+                file.filename
+            )));
+        }
+
+        Ok(NewMessage {
+            body: self.body,
+            attachments: self.attachments,
+            images: self.images,
+        })
+    }
 }
 
 /// Parameters for people search.
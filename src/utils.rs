@@ -1,7 +1,12 @@
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
 use rand::Rng;
+use time::OffsetDateTime;
 use tokio::time::{sleep, Duration};
 
-use crate::UniformResourceName;
+use crate::LinkedinError;
 
 /// Evade detection by sleeping for a random duration.
 pub async fn evade() {
@@ -9,11 +14,48 @@ pub async fn evade() {
     sleep(Duration::from_secs(delay)).await;
 }
 
-/// Extract ID
-pub(crate) fn get_id_from_urn(urn: Option<UniformResourceName>) -> Option<String> {
-    if let Some(secure_urn) = urn {
-        Some(secure_urn.id)
-    } else {
-        None
+/// Convert a LinkedIn millisecond epoch timestamp into an `OffsetDateTime`.
+pub(crate) fn datetime_from_millis(millis: i64) -> Option<OffsetDateTime> {
+    OffsetDateTime::from_unix_timestamp_nanos(millis as i128 * 1_000_000).ok()
+}
+
+/// A random base64 tracking id, as LinkedIn's web client sends with invitations.
+pub fn tracking_id() -> String {
+    let bytes: [u8; 16] = rand::thread_rng().gen();
+    base64_encode(&bytes)
+}
+
+/// Standard, padded base64 (RFC 4648).
+pub fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
     }
+    encoded
+}
+
+/// Write a file through a temporary sibling and rename it into place, so an
+/// interrupted write leaves the previous contents intact.
+pub(crate) fn write_atomically(
+    path: &Path,
+    write: impl FnOnce(&mut BufWriter<File>) -> Result<(), LinkedinError>,
+) -> Result<(), LinkedinError> {
+    let temporary = path.with_extension("tmp");
+    let mut writer = BufWriter::new(File::create(&temporary)?);
+    write(&mut writer)?;
+    writer.flush()?;
+    fs::rename(temporary, path)?;
+    Ok(())
 }
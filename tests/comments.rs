@@ -0,0 +1,72 @@
+use linkedin_api::types::{Comment, ReactionType};
+use serde_json::json;
+
+#[test]
+fn test_parse_comment_with_replies() {
+    let element = json!({
+        "urn": "urn:li:comment:(activity:7100000000000000000,7100000000000000001)",
+        "createdTime": 1700000000000i64,
+        "commenter": {
+            "com.linkedin.voyager.feed.MemberActor": {
+                "miniProfile": {
+                    "entityUrn": "urn:li:fs_miniProfile:ACoAAB",
+                    "publicIdentifier": "jane-doe",
+                    "firstName": "Jane",
+                    "lastName": "Doe"
+                }
+            }
+        },
+        "commentV2": { "text": "Nice post!" },
+        "socialDetail": {
+            "totalSocialActivityCounts": { "numLikes": 4 },
+            "comments": { "elements": [{
+                "entityUrn": "urn:li:comment:(activity:7100000000000000000,7100000000000000002)",
+                "parentCommentUrn": "urn:li:comment:(activity:7100000000000000000,7100000000000000001)",
+                "commentV2": { "text": "Thanks" }
+            }]}
+        }
+    });
+
+    let comment = Comment::from_voyager(&element);
+    assert_eq!(comment.urn.as_ref().unwrap().namespace, "comment");
+    assert_eq!(comment.text.text, "Nice post!");
+    assert_eq!(
+        comment
+            .commenter
+            .as_ref()
+            .unwrap()
+            .public_identifier
+            .as_deref(),
+        Some("jane-doe")
+    );
+    assert!(comment.created_at.is_some());
+    assert_eq!(comment.likes, 4);
+    // Without numComments the inline replies are counted
+    assert_eq!(comment.reply_count, 1);
+
+    let reply = &comment.replies[0];
+    assert_eq!(reply.text.text, "Thanks");
+    assert_eq!(reply.parent_urn, comment.urn);
+    assert!(reply.commenter.is_none());
+}
+
+#[test]
+fn test_reaction_type_names() {
+    assert_eq!(
+        "PRAISE".parse::<ReactionType>().unwrap(),
+        ReactionType::Celebrate
+    );
+    assert!("praise".parse::<ReactionType>().is_err());
+
+    for reaction in ReactionType::ALL {
+        assert_eq!(reaction.as_str().parse::<ReactionType>().unwrap(), reaction);
+        assert_eq!(
+            serde_json::to_value(reaction).unwrap(),
+            json!(reaction.as_str())
+        );
+    }
+    assert_eq!(
+        serde_json::from_value::<ReactionType>(json!("EMPATHY")).unwrap(),
+        ReactionType::Love
+    );
+}
@@ -0,0 +1,155 @@
+use linkedin_api::export::{
+    markdown_entry, markdown_header, mbox_entry, ExportArchive, ExportFormat,
+};
+use linkedin_api::types::{Conversation, Message};
+use serde_json::json;
+use std::fs;
+use std::path::PathBuf;
+
+fn conversation() -> Conversation {
+    Conversation::from_voyager(&json!({
+        "entityUrn": "urn:li:fs_conversation:2-YWJj==",
+        "participants": [{
+            "com.linkedin.voyager.messaging.MessagingMember": {
+                "miniProfile": {
+                    "firstName": "Jane",
+                    "lastName": "Doe",
+                    "publicIdentifier": "jane-doe"
+                }
+            }
+        }]
+    }))
+    .unwrap()
+}
+
+fn message(text: &str) -> Message {
+    message_at("5-ZGVm", 1700000000000, text)
+}
+
+fn message_at(event_id: &str, created_at: i64, text: &str) -> Message {
+    Message::from_voyager(&json!({
+        "entityUrn": format!("urn:li:fs_event:(2-YWJj==,{event_id})"),
+        "createdAt": created_at,
+        "from": {
+            "com.linkedin.voyager.messaging.MessagingMember": {
+                "miniProfile": { "firstName": "Bob", "lastName": "Smith", "publicIdentifier": "bob" }
+            }
+        },
+        "eventContent": {
+            "com.linkedin.voyager.messaging.event.MessageEvent": {
+                "attributedBody": { "text": text }
+            }
+        }
+    }))
+}
+
+#[test]
+fn test_mbox_entry() {
+    let entry = mbox_entry(&conversation(), &message("Hi Jane\nFrom the team"));
+
+    assert!(entry.starts_with("From bob@linkedin.com Tue Nov 14 22:13:20 2023\n"));
+    assert!(entry.contains("From: \"Bob Smith\" <bob@linkedin.com>\n"));
+    assert!(entry.contains("To: \"Jane Doe\" <jane-doe@linkedin.com>\n"));
+    assert!(entry.contains("Date: Tue, 14 Nov 2023 22:13:20 +0000\n"));
+    assert!(entry.contains("Subject: LinkedIn conversation with Jane Doe\n"));
+    assert!(entry.contains("Message-ID: <%282-YWJj==%2C5-ZGVm%29@linkedin.com>\n"));
+    assert!(entry.contains("X-LinkedIn-Conversation: 2-YWJj==\n"));
+    // Body lines that look like separators are quoted
+    assert!(entry.ends_with("\n\nHi Jane\n>From the team\n\n"));
+}
+
+#[test]
+fn test_markdown_transcript() {
+    let transcript = markdown_header(&conversation()) + &markdown_entry(&message("Hello"));
+
+    assert_eq!(
+        transcript,
+        "# LinkedIn conversation with Jane Doe\n\n- Jane Doe\n\n\
+         **Bob Smith** — Tue, 14 Nov 2023 22:13:20 +0000\n\nHello\n\n"
+    );
+}
+
+#[test]
+fn test_mbox_encodes_non_ascii_headers() {
+    let mut conversation = conversation();
+    conversation.participants[0].first_name = Some("Zoë".into());
+
+    let entry = mbox_entry(&conversation, &message("Hi"));
+
+    assert!(entry.contains("To: =?utf-8?Q?Zo=C3=AB_Doe?= <jane-doe@linkedin.com>\n"));
+    assert!(entry.contains("Subject: =?utf-8?Q?LinkedIn_conversation_with_Zo=C3=AB_Doe?=\n"));
+    assert!(entry.is_ascii());
+}
+
+fn archive_dir(name: &str) -> PathBuf {
+    let directory =
+        std::env::temp_dir().join(format!("linkedin-export-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&directory);
+    directory
+}
+
+#[test]
+fn test_incremental_export() {
+    let directory = archive_dir("incremental");
+    let mut conversation = conversation();
+    let first = message_at("5-MQ", 1700000000000, "First");
+    let second = message_at("5-Mg", 1700000060000, "Second");
+
+    let mut archive = ExportArchive::open(&directory, ExportFormat::Markdown).unwrap();
+    assert!(archive.needs_export(&conversation));
+    assert_eq!(
+        archive
+            .append(&conversation, std::slice::from_ref(&first))
+            .unwrap(),
+        1
+    );
+
+    // A later run only writes what's newer than the last export
+    let mut archive = ExportArchive::open(&directory, ExportFormat::Markdown).unwrap();
+    conversation.last_activity_at = first.created_at;
+    assert!(!archive.needs_export(&conversation));
+    conversation.last_activity_at = second.created_at;
+    assert!(archive.needs_export(&conversation));
+    assert_eq!(archive.append(&conversation, &[first, second]).unwrap(), 1);
+    assert_eq!(
+        archive.exported_until(&conversation.id),
+        conversation.last_activity_at
+    );
+
+    let transcript = fs::read_to_string(directory.join("2-YWJj==.md")).unwrap();
+    assert_eq!(transcript.matches("First").count(), 1);
+    assert_eq!(transcript.matches("Second").count(), 1);
+
+    // Without a known last activity the conversation is always checked
+    conversation.last_activity_at = None;
+    assert!(archive.needs_export(&conversation));
+
+    fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn test_interrupted_export_is_rolled_back() {
+    let directory = archive_dir("rollback");
+    let conversation = conversation();
+
+    let mut archive = ExportArchive::open(&directory, ExportFormat::Mbox).unwrap();
+    archive
+        .append(&conversation, &[message_at("5-MQ", 1700000000000, "First")])
+        .unwrap();
+    let mbox = directory.join("inbox.mbox");
+    let length = fs::metadata(&mbox).unwrap().len();
+
+    // Simulate a crash after starting an append but before recording it
+    let state_path = directory.join(".linkedin-export.json");
+    let mut state: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&state_path).unwrap()).unwrap();
+    state["pending"] = json!({ "path": mbox, "length": length });
+    fs::write(&state_path, state.to_string()).unwrap();
+    let mut file = fs::OpenOptions::new().append(true).open(&mbox).unwrap();
+    std::io::Write::write_all(&mut file, b"From partial").unwrap();
+
+    ExportArchive::open(&directory, ExportFormat::Mbox).unwrap();
+    assert_eq!(fs::metadata(&mbox).unwrap().len(), length);
+
+    fs::remove_dir_all(directory).unwrap();
+}
@@ -0,0 +1,120 @@
+use linkedin_api::feed::FeedFilter;
+use linkedin_api::types::{FeedUpdate, SpanKind};
+use serde_json::json;
+use time::{Duration, OffsetDateTime};
+
+#[test]
+fn test_parse_article_share() {
+    let element = json!({
+        "value": {
+            "com.linkedin.voyager.feed.render.UpdateV2": {
+                "updateMetadata": { "urn": "urn:li:activity:7100000000000000000" },
+                "actor": {
+                    "urn": "urn:li:member:123",
+                    "name": { "text": "Jane Doe" },
+                    "description": { "text": "Engineer at Example" }
+                },
+                "commentary": {
+                    "text": {
+                        "text": "Thanks Bob Smith #rust",
+                        "attributes": [
+                            {
+                                "start": 7,
+                                "length": 9,
+                                "type": "PROFILE_MENTION",
+                                "miniProfile": {
+                                    "entityUrn": "urn:li:fs_miniProfile:ACoAAB",
+                                    "publicIdentifier": "bob-smith"
+                                }
+                            },
+                            { "start": 17, "length": 5, "type": "HASHTAG" }
+                        ]
+                    }
+                },
+                "content": {
+                    "com.linkedin.voyager.feed.render.ArticleComponent": {
+                        "navigationContext": { "actionTarget": "https://example.com/post" },
+                        "title": { "text": "An article" }
+                    }
+                },
+                "socialDetail": {
+                    "totalSocialActivityCounts": { "numLikes": 12, "numComments": 3, "numShares": 1 }
+                }
+            }
+        }
+    });
+
+    let update = FeedUpdate::from_update_v2(&element);
+    let FeedUpdate::ArticleShare { post, article } = &update else {
+        panic!("expected an article share, got {update:?}");
+    };
+
+    assert_eq!(post.urn.as_ref().unwrap().id, "7100000000000000000");
+    assert!(post.published_at.is_some());
+    assert_eq!(
+        post.actor.as_ref().unwrap().name.as_deref(),
+        Some("Jane Doe")
+    );
+    assert_eq!(post.social_counts.likes, 12);
+    assert_eq!(post.social_counts.reposts, 1);
+    assert_eq!(article.title.as_deref(), Some("An article"));
+
+    let commentary = post.commentary.as_ref().unwrap();
+    assert_eq!(commentary.span_text(&commentary.spans[0]), "Bob Smith");
+    assert!(matches!(
+        &commentary.spans[1].kind,
+        SpanKind::Hashtag { tag } if tag == "rust"
+    ));
+}
+
+#[test]
+fn test_parse_reshare() {
+    let element = json!({
+        "updateMetadata": { "urn": "urn:li:activity:2" },
+        "resharedUpdate": {
+            "updateMetadata": { "urn": "urn:li:activity:1" },
+            "commentary": { "text": { "text": "original" } }
+        }
+    });
+
+    let update = FeedUpdate::from_update_v2(&element);
+    let FeedUpdate::Reshare { original, .. } = &update else {
+        panic!("expected a reshare, got {update:?}");
+    };
+
+    let original = original.as_ref().unwrap();
+    assert!(matches!(**original, FeedUpdate::TextPost { .. }));
+    assert_eq!(
+        original.post().commentary.as_ref().unwrap().text,
+        "original"
+    );
+}
+
+/// A text post whose activity id encodes `published_at`
+fn post_at(published_at: OffsetDateTime) -> FeedUpdate {
+    let millis = (published_at.unix_timestamp_nanos() / 1_000_000) as u64;
+    FeedUpdate::from_update_v2(&json!({
+        "updateMetadata": { "urn": format!("urn:li:activity:{}", millis << 22) }
+    }))
+}
+
+#[test]
+fn test_feed_filter_window_and_dedupe() {
+    let since = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+    let old = post_at(since - Duration::seconds(1));
+    let fresh = post_at(since + Duration::seconds(1));
+    let newer = post_at(since + Duration::seconds(2));
+    let undated = FeedUpdate::from_update_v2(&json!({ "commentary": {} }));
+
+    let mut filter = FeedFilter::new(Some(since));
+    assert!(filter.is_too_old(&old));
+    assert!(!filter.is_too_old(&fresh));
+    assert!(!filter.is_too_old(&undated));
+
+    let first = filter.take_fresh(vec![fresh.clone(), old, fresh.clone(), undated.clone()]);
+    assert_eq!(first, vec![fresh.clone(), undated.clone()]);
+
+    // Already-seen updates are dropped; updates without a URN can't be deduped
+    let second = filter.take_fresh(vec![newer.clone(), fresh, undated.clone()]);
+    assert_eq!(second, vec![newer, undated]);
+}
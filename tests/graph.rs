@@ -0,0 +1,135 @@
+use linkedin_api::crawler::{CrawlEdge, CrawlGraph, CrawlNode};
+use linkedin_api::graph::{to_csv_edges, to_csv_nodes, to_dot, to_gexf, to_graphml};
+use linkedin_api::types::{Connection, MiniProfile};
+use serde_json::json;
+
+fn node(urn_id: &str, name: &str, headline: Option<&str>, depth: usize) -> CrawlNode {
+    CrawlNode {
+        urn_id: urn_id.into(),
+        public_id: None,
+        name: Some(name.into()),
+        headline: headline.map(Into::into),
+        distance: Some("DISTANCE_2".into()),
+        depth,
+    }
+}
+
+fn graph() -> CrawlGraph {
+    let mut graph = CrawlGraph::default();
+    graph.add_node(node(
+        "ACoA1",
+        "Jane Doe",
+        Some("Engineer at Example, Inc"),
+        0,
+    ));
+    graph.add_node(node(
+        "ACoA2",
+        "Bob \"B\" Smith",
+        Some("R&D @ Acme | Hiring"),
+        1,
+    ));
+    graph.add_edge("ACoA2", "ACoA1");
+    graph
+}
+
+#[test]
+fn test_company_from_headline() {
+    let company = |headline| node("a", "a", headline, 0).company();
+
+    assert_eq!(company(Some("Engineer at Example")), Some("Example".into()));
+    assert_eq!(company(Some("CTO AT Acme | Speaker")), Some("Acme".into()));
+    assert_eq!(company(Some("Founder @ Foo, ex-Bar")), Some("Foo".into()));
+    assert_eq!(
+        company(Some("Looking at opportunities at Baz")),
+        Some("Baz".into())
+    );
+    assert_eq!(company(Some("Freelance designer")), None);
+    assert_eq!(company(None), None);
+}
+
+#[test]
+fn test_graph_formats() {
+    let graph = graph();
+
+    let graphml = to_graphml(&graph);
+    assert!(graphml.contains("<graph id=\"linkedin\" edgedefault=\"undirected\">"));
+    assert!(graphml.contains("<data key=\"name\">Bob &quot;B&quot; Smith</data>"));
+    assert!(graphml.contains("<data key=\"company\">Acme</data>"));
+    assert!(graphml.contains("<edge source=\"ACoA1\" target=\"ACoA2\"/>"));
+
+    let gexf = to_gexf(&graph);
+    assert!(gexf.contains("<node id=\"ACoA1\" label=\"Jane Doe\">"));
+    assert!(gexf.contains("<attvalue for=\"1\" value=\"Example\"/>"));
+    assert!(gexf.contains("<edge id=\"0\" source=\"ACoA1\" target=\"ACoA2\"/>"));
+
+    let dot = to_dot(&graph);
+    assert!(dot.starts_with("graph linkedin {\n"));
+    assert!(dot.contains("\"ACoA2\" [label=\"Bob \\\"B\\\" Smith\""));
+    assert!(dot.contains("  \"ACoA1\" -- \"ACoA2\";\n"));
+
+    assert_eq!(
+        to_csv_nodes(&graph),
+        "id,name,headline,company,distance,depth\n\
+         ACoA1,Jane Doe,\"Engineer at Example, Inc\",Example,DISTANCE_2,0\n\
+         ACoA2,\"Bob \"\"B\"\" Smith\",R&D @ Acme | Hiring,Acme,DISTANCE_2,1\n"
+    );
+    assert_eq!(to_csv_edges(&graph), "source,target\nACoA1,ACoA2\n");
+}
+
+#[test]
+fn test_graph_from_connections() {
+    let connection = |urn_id: &str, public_id: &str| Connection {
+        urn_id: urn_id.into(),
+        public_id: public_id.into(),
+        distance: "DISTANCE_2".into(),
+        name: Some("Jane Doe".into()),
+        headline: Some("Engineer at Example".into()),
+    };
+    let mut graph = CrawlGraph::default();
+    graph.add_connections(
+        "ACoA1",
+        &[
+            connection("ACoA2", "jane-doe"),
+            connection("", "no-urn"),
+            connection("ACoA3", ""),
+        ],
+        1,
+    );
+
+    assert_eq!(graph.nodes.len(), 2);
+    assert_eq!(graph.nodes["ACoA2"].public_id.as_deref(), Some("jane-doe"));
+    assert_eq!(graph.nodes["ACoA3"].public_id, None);
+    assert_eq!(graph.nodes["ACoA3"].company().as_deref(), Some("Example"));
+    assert!(graph.edges.contains(&CrawlEdge::new("ACoA1", "ACoA3")));
+    assert_eq!(graph.edges.len(), 2);
+}
+
+#[test]
+fn test_graph_from_mini_profiles() {
+    let profiles: Vec<MiniProfile> = serde_json::from_value(json!([
+        {
+            "dashEntityUrn": "urn:li:fsd_profile:ACoA2",
+            "entityUrn": "urn:li:fs_miniProfile:ACoA2",
+            "publicIdentifier": "jane-doe",
+            "firstName": "Jane",
+            "lastName": "Doe",
+            "occupation": "Designer @ Acme"
+        },
+        { "entityUrn": "urn:li:fs_miniProfile:ACoA3", "firstName": "Bob" },
+        { "firstName": "No", "lastName": "Urn" }
+    ]))
+    .unwrap();
+
+    let node = CrawlNode::from_mini_profile(&profiles[0], 1).unwrap();
+    assert_eq!(node.urn_id, "ACoA2");
+    assert_eq!(node.name.as_deref(), Some("Jane Doe"));
+    assert_eq!(node.company().as_deref(), Some("Acme"));
+    assert!(CrawlNode::from_mini_profile(&profiles[2], 1).is_none());
+
+    let mut graph = CrawlGraph::default();
+    graph.add_mini_profiles("ACoA1", &profiles, 1);
+    assert_eq!(graph.nodes.keys().collect::<Vec<_>>(), ["ACoA2", "ACoA3"]);
+    assert_eq!(graph.nodes["ACoA3"].name.as_deref(), Some("Bob"));
+    assert!(graph.edges.contains(&CrawlEdge::new("ACoA2", "ACoA1")));
+    assert_eq!(graph.edges.len(), 2);
+}
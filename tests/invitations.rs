@@ -0,0 +1,176 @@
+use linkedin_api::triage::{TriageDecision, TriageRules};
+use linkedin_api::types::{Invitation, InvitationResult, InvitationType, SentInvitation};
+use linkedin_api::utils::{base64_encode, tracking_id};
+use serde_json::json;
+
+#[test]
+fn test_parse_sent_invitation() {
+    let element = json!({
+        "invitation": {
+            "entityUrn": "urn:li:fs_relInvitation:7100000000000000000",
+            "sharedSecret": "abc123",
+            "sentTime": 1700000000000i64,
+            "message": "Great meeting you",
+            "toMember": {
+                "entityUrn": "urn:li:fs_miniProfile:ACoAAB",
+                "firstName": "Jane",
+                "lastName": "Doe"
+            }
+        }
+    });
+
+    let invitation = SentInvitation::from_voyager(&element).unwrap();
+
+    assert_eq!(invitation.entity_urn.id, "7100000000000000000");
+    assert_eq!(invitation.shared_secret, "abc123");
+    assert_eq!(invitation.message.as_deref(), Some("Great meeting you"));
+    assert_eq!(invitation.sent_at.unwrap().unix_timestamp(), 1_700_000_000);
+    assert_eq!(
+        invitation.recipient.unwrap().first_name.as_deref(),
+        Some("Jane")
+    );
+
+    assert!(SentInvitation::from_voyager(&json!({ "invitation": {} })).is_none());
+}
+
+#[test]
+fn test_parse_received_invitation() {
+    let element = json!({
+        "invitation": {
+            "entityUrn": "urn:li:fs_relInvitation:7200000000000000000",
+            "sharedSecret": "xyz",
+            "invitationType": "CONNECTION",
+            "sentTime": 1700000000000i64,
+            "message": "Hi, let's connect",
+            "fromMember": {
+                "entityUrn": "urn:li:fs_miniProfile:ACoAAC",
+                "firstName": "Bob",
+                "occupation": "Recruiter at Example"
+            }
+        },
+        "insights": [{
+            "sharedInsight": {
+                "com.linkedin.voyager.relationships.shared.connection.SharedConnectionsInsight": {
+                    "totalCount": 12,
+                    "connections": [{ "firstName": "Jane" }]
+                }
+            }
+        }]
+    });
+
+    let invitation = Invitation::from_voyager(&element).unwrap();
+
+    assert_eq!(invitation.entity_urn.id, "7200000000000000000");
+    assert_eq!(invitation.invitation_type, InvitationType::Connection);
+    assert_eq!(invitation.message.as_deref(), Some("Hi, let's connect"));
+    assert_eq!(
+        invitation.sender.unwrap().occupation.as_deref(),
+        Some("Recruiter at Example")
+    );
+    assert_eq!(invitation.shared_connections, 12);
+    assert_eq!(invitation.shared_connection_profiles.len(), 1);
+
+    let page = json!({
+        "invitation": {
+            "entityUrn": "urn:li:fs_relInvitation:1",
+            "sharedSecret": "s",
+            "invitationType": "CONNECTION",
+            "genericInvitationType": "ORGANIZATION"
+        }
+    });
+    let page = Invitation::from_voyager(&page).unwrap();
+    assert_eq!(page.invitation_type, InvitationType::Page);
+    assert!(page.sender.is_none());
+}
+
+fn invitation(shared_connections: u64, headline: &str) -> Invitation {
+    Invitation::from_voyager(&json!({
+        "invitation": {
+            "entityUrn": "urn:li:fs_relInvitation:1",
+            "sharedSecret": "s",
+            "fromMember": { "occupation": headline }
+        },
+        "insights": [{
+            "sharedInsight": {
+                "com.linkedin.voyager.relationships.shared.connection.SharedConnectionsInsight": {
+                    "totalCount": shared_connections
+                }
+            }
+        }]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_triage_rules() {
+    let rules = TriageRules::new()
+        .min_shared_connections(5)
+        .company("Example")
+        .headline_keyword("engineer")
+        .headline_keyword("developer");
+
+    let (decision, reason) = rules.decide(&invitation(8, "Senior Engineer at Example"));
+    assert_eq!(decision, TriageDecision::Accept);
+    assert!(reason.contains("8 shared connections"));
+
+    let (decision, _) = rules.decide(&invitation(2, "Senior Engineer at Example"));
+    assert_eq!(decision, TriageDecision::Ignore);
+
+    let (decision, reason) = rules.decide(&invitation(8, "Developer at Other Co"));
+    assert_eq!(decision, TriageDecision::Ignore);
+    assert_eq!(reason, "headline matches no company");
+
+    let mut page = invitation(50, "Engineer at Example");
+    page.invitation_type = InvitationType::Page;
+    assert_eq!(rules.decide(&page).0, TriageDecision::Skip);
+}
+
+#[test]
+fn test_triage_without_rules_skips() {
+    let (decision, reason) = TriageRules::new().decide(&invitation(50, "Engineer at Example"));
+    assert_eq!(decision, TriageDecision::Skip);
+    assert_eq!(reason, "no rules configured");
+}
+
+#[test]
+fn test_invitation_result_from_error_code() {
+    assert_eq!(
+        InvitationResult::from_error_code("CANT_RESEND_YET"),
+        Some(InvitationResult::AlreadyPending)
+    );
+    assert_eq!(
+        InvitationResult::from_error_code("ALREADY_FIRST_DEGREE_CONNECTION"),
+        Some(InvitationResult::AlreadyConnected)
+    );
+    assert_eq!(
+        InvitationResult::from_error_code("REQUIRES_EMAIL"),
+        Some(InvitationResult::EmailRequired)
+    );
+    assert_eq!(
+        InvitationResult::from_error_code("FUSE_LIMIT_EXCEEDED"),
+        Some(InvitationResult::WeeklyLimitReached)
+    );
+    assert_eq!(InvitationResult::from_error_code("SOMETHING_ELSE"), None);
+}
+
+#[test]
+fn test_tracking_id_is_base64() {
+    // RFC 4648 test vectors
+    for (input, encoded) in [
+        ("", ""),
+        ("f", "Zg=="),
+        ("fo", "Zm8="),
+        ("foo", "Zm9v"),
+        ("foob", "Zm9vYg=="),
+        ("fooba", "Zm9vYmE="),
+        ("foobar", "Zm9vYmFy"),
+    ] {
+        assert_eq!(base64_encode(input.as_bytes()), encoded);
+    }
+    assert_eq!(base64_encode(&[0xfb, 0xff]), "+/8=");
+
+    let id = tracking_id();
+    assert_eq!(id.len(), 24);
+    assert!(id.ends_with("=="));
+    assert_ne!(id, tracking_id());
+}
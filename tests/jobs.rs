@@ -0,0 +1,82 @@
+use linkedin_api::types::{JobPosting, ProfileView, Skill};
+use serde_json::{json, Value};
+
+fn profile(headline: &str, positions: Value, skills: &[&str]) -> ProfileView {
+    let view = |elements: Value| {
+        json!({
+            "elements": elements,
+            "paging": { "count": 10, "links": [], "start": 0, "total": 0 },
+            "profileId": "ACoAAB"
+        })
+    };
+    let mut profile: ProfileView = serde_json::from_value(json!({
+        "certificationView": view(json!([])),
+        "courseView": view(json!([])),
+        "educationView": view(json!([])),
+        "honorView": view(json!([])),
+        "languageView": view(json!([])),
+        "organizationView": view(json!([])),
+        "patentView": view(json!([])),
+        "positionGroupView": view(json!([])),
+        "positionView": view(positions),
+        "primaryLocale": { "country": "US", "language": "en" },
+        "profile": { "headline": headline, "locationName": "Berlin, Germany" },
+        "projectView": view(json!([])),
+        "publicationView": view(json!([])),
+        "skillView": view(json!([])),
+        "summaryTreasuryMediaCount": 0,
+        "summaryTreasuryMedias": [],
+        "testScoreView": view(json!([])),
+        "volunteerCauseView": view(json!([])),
+        "volunteerExperienceView": view(json!([]))
+    }))
+    .unwrap();
+    profile.skills = skills
+        .iter()
+        .map(|name| Skill {
+            entity_urn: None,
+            name: name.to_string(),
+        })
+        .collect();
+    profile
+}
+
+fn posting(skills: &[&str]) -> JobPosting {
+    JobPosting {
+        job_posting_id: "1".into(),
+        entity_urn: None,
+        title: Some("Senior Rust Engineer".into()),
+        description: None,
+        company_name: Some("Example".into()),
+        formatted_location: Some("Berlin, Berlin, Germany".into()),
+        listed_at: None,
+        skills: skills.iter().map(|s| s.to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_score_profile() {
+    let engineer = profile(
+        "Backend developer",
+        json!([{ "title": "Rust Engineer", "locationName": "Munich, Germany" }]),
+        &["rust", "PostgreSQL "],
+    );
+
+    let result = posting(&["Rust", "PostgreSQL", "Kubernetes", "Go"]).score_profile(&engineer);
+    assert_eq!(result.matched_skills, ["Rust", "PostgreSQL"]);
+    assert_eq!(result.missing_skills, ["Kubernetes", "Go"]);
+    assert!(result.title_match);
+    assert!(result.location_match);
+    assert!((result.score - (0.6 * 0.5 + 0.25 + 0.15)).abs() < 1e-9);
+
+    // Without posting skills the other weights are rescaled
+    let result = posting(&[]).score_profile(&engineer);
+    assert!((result.score - 1.0).abs() < 1e-9);
+
+    let unrelated = profile("Florist", json!([]), &[]);
+    let result = posting(&["Rust"]).score_profile(&unrelated);
+    assert!(!result.title_match);
+    // Only the profile's own location matches
+    assert!(result.location_match);
+    assert!((result.score - 0.15).abs() < 1e-9);
+}
@@ -1,4 +1,8 @@
-use linkedin_api::types::{Identity, SearchPeopleParams};
+use linkedin_api::inbox::InboxFilter;
+use linkedin_api::types::{
+    ConnectionSort, ConversationAction, FollowKind, Identity, NewPost, PostVisibility,
+    ReactionType, SearchPeopleParams, UniformResourceName,
+};
 use linkedin_api::{Linkedin, LinkedinError};
 use std::env;
 
@@ -22,7 +26,7 @@ async fn test_get_profile() -> Result<(), LinkedinError> {
     let api = Linkedin::new(&identity, true).await?;
     let profile = api.get_profile(&profile_id).await?;
 
-    assert!(!profile.profile_id.is_empty());
+    assert!(!profile.profile.profile_id.is_empty());
     Ok(())
 }
 
@@ -109,11 +113,12 @@ async fn test_send_message_to_conversation() -> Result<(), LinkedinError> {
     let (identity, _, conversation_id) = get_test_credentials();
     let api = Linkedin::new(&identity, false).await?;
 
-    let err = api
+    let sent = api
         .send_message(Some(&conversation_id), None, "test message from rust")
         .await?;
 
-    println!("Send message error: {}", err);
+    assert_eq!(sent.conversation_urn.id, conversation_id);
+    println!("Sent message: {}", sent.event_urn);
     Ok(())
 }
 
@@ -126,3 +131,238 @@ async fn test_get_profile_skills() -> Result<(), LinkedinError> {
     println!("Found {} skills", skills.len());
     Ok(())
 }
+
+#[tokio::test]
+async fn test_job_skill_match() -> Result<(), LinkedinError> {
+    let (identity, profile_id, _) = get_test_credentials();
+    let job_id = env::var("TEST_JOB_ID").expect("TEST_JOB_ID not set");
+    let api = Linkedin::new(&identity, false).await?;
+
+    let job = api.get_job(&job_id).await?;
+    let profile = api.get_profile(&profile_id).await?;
+    let job_match = job.score_profile(&profile);
+
+    assert!((0.0..=1.0).contains(&job_match.score));
+    assert_eq!(
+        job_match.matched_skills.len() + job_match.missing_skills.len(),
+        job.skills.len()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "publishes a real post"]
+async fn test_create_post() -> Result<(), LinkedinError> {
+    let (identity, _, _) = get_test_credentials();
+    let api = Linkedin::new(&identity, false).await?;
+
+    let post = NewPost::builder()
+        .text("test post from rust ")
+        .hashtag("rust")
+        .visibility(PostVisibility::Connections)
+        .build()?;
+    let urn = api.create_post(&post).await?;
+
+    println!("Created post: {}", urn);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_comments_and_reactions() -> Result<(), LinkedinError> {
+    let (identity, profile_id, _) = get_test_credentials();
+    let api = Linkedin::new(&identity, false).await?;
+
+    let updates = api
+        .get_profile_updates(Some(&profile_id), None, Some(1))
+        .await?;
+    let Some(post) = updates.first().and_then(|u| u.post().urn.clone()) else {
+        return Ok(());
+    };
+
+    let comments = api.get_comments(&post, 0, 10).await?;
+    let likes = api
+        .get_reactions(&post, Some(ReactionType::Like), 0, 10)
+        .await?;
+
+    println!("Found {} comments, {} likes", comments.len(), likes.len());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_home_feed() -> Result<(), LinkedinError> {
+    let (identity, _, _) = get_test_credentials();
+    let api = Linkedin::new(&identity, false).await?;
+
+    let mut feed = api.home_feed().page_size(10);
+    let first = feed.poll().await?;
+    let second = feed.poll().await?;
+
+    // Everything on the first poll is remembered, so the second only has new items
+    assert!(second.iter().all(|update| !first
+        .iter()
+        .any(|seen| seen.post().urn == update.post().urn)));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_inbox_unread() -> Result<(), LinkedinError> {
+    let (identity, _, _) = get_test_credentials();
+    let api = Linkedin::new(&identity, false).await?;
+
+    let mut inbox = api.inbox().filter(InboxFilter::Unread);
+    let mut unread = 0;
+    while let Some(conversation) = inbox.next().await? {
+        assert!(!conversation.read || conversation.unread_count > 0);
+        unread += 1;
+    }
+
+    println!("Found {} unread conversations", unread);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_archive_and_unarchive_conversation() -> Result<(), LinkedinError> {
+    let (identity, _, conversation_id) = get_test_credentials();
+    let api = Linkedin::new(&identity, false).await?;
+
+    let update = api.archive_conversation(&conversation_id).await?;
+    assert_eq!(update.conversation_urn.id, conversation_id);
+    assert_eq!(update.action, ConversationAction::Archive);
+
+    api.unarchive_conversation(&conversation_id).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_group_conversation_participants() -> Result<(), LinkedinError> {
+    let (identity, _, conversation_id) = get_test_credentials();
+    let api = Linkedin::new(&identity, false).await?;
+
+    let participants = api.get_conversation_participants(&conversation_id).await?;
+    assert!(participants.iter().all(|p| p.urn().is_some()));
+
+    println!("Found {} participants", participants.len());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_send_invitation_rejects_long_note() -> Result<(), LinkedinError> {
+    let (identity, profile_id, _) = get_test_credentials();
+    let api = Linkedin::new(&identity, false).await?;
+
+    let note = "a".repeat(301);
+    let result = api.send_invitation(&profile_id, Some(&note)).await;
+
+    assert!(matches!(result, Err(LinkedinError::InvalidInput(_))));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_sent_invitations() -> Result<(), LinkedinError> {
+    let (identity, _, _) = get_test_credentials();
+    let api = Linkedin::new(&identity, false).await?;
+
+    let invitations = api.get_all_sent_invitations().await?;
+    let month_ago = time::OffsetDateTime::now_utc() - time::Duration::days(30);
+    let stale = invitations
+        .iter()
+        .filter(|i| i.sent_at.is_some_and(|t| t < month_ago))
+        .count();
+
+    println!(
+        "Found {} sent invitations, {} stale",
+        invitations.len(),
+        stale
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_connections() -> Result<(), LinkedinError> {
+    let (identity, _, _) = get_test_credentials();
+    let api = Linkedin::new(&identity, false).await?;
+
+    let connections = api
+        .get_connections(ConnectionSort::RecentlyAdded, 0, 10)
+        .await?;
+    assert!(connections
+        .windows(2)
+        .all(|pair| pair[0].connected_at >= pair[1].connected_at));
+
+    println!("Found {} recent connections", connections.len());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_shared_connections() -> Result<(), LinkedinError> {
+    let (identity, profile_id, _) = get_test_credentials();
+    let api = Linkedin::new(&identity, false).await?;
+
+    let shared = api.get_shared_connections(&profile_id).await?;
+    println!(
+        "Found {:?} shared connections, {} listed",
+        shared.count,
+        shared.connections.len()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_crawl_from_profile() -> Result<(), LinkedinError> {
+    let (identity, profile_id, _) = get_test_credentials();
+    let api = Linkedin::new(&identity, false).await?;
+
+    // connectionOf takes the member URN id, not the public id
+    let profile = api.get_profile(&profile_id).await?;
+    let seed = UniformResourceName::parse(
+        profile
+            .entity_urn
+            .as_deref()
+            .expect("profile has an entity urn"),
+    )?;
+    let graph = api
+        .crawler(&[seed])
+        .max_depth(1)
+        .fan_out(10)
+        .request_budget(2)
+        .run()
+        .await?;
+
+    assert!(graph.nodes.len() <= 11);
+    println!(
+        "Crawled {} nodes, {} edges",
+        graph.nodes.len(),
+        graph.edges.len()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_following() -> Result<(), LinkedinError> {
+    let (identity, _, _) = get_test_credentials();
+    let api = Linkedin::new(&identity, false).await?;
+
+    let companies = api.get_following(FollowKind::Company).await?;
+    assert!(companies.iter().all(|c| c.kind == FollowKind::Company));
+    if let Some(company) = companies.first() {
+        assert!(api.is_following(&company.urn).await?);
+    }
+
+    println!("Following {} companies", companies.len());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_profile_network_info() -> Result<(), LinkedinError> {
+    let (identity, profile_id, _) = get_test_credentials();
+    let api = Linkedin::new(&identity, false).await?;
+
+    let info = api.get_profile_network_info(&profile_id).await?;
+    assert!(info.distance.is_some());
+
+    println!(
+        "{} followers, {:?} connections, {:?}",
+        info.followers_count, info.connections_count, info.distance
+    );
+    Ok(())
+}
@@ -0,0 +1,239 @@
+use linkedin_api::inbox::InboxFilter;
+use linkedin_api::types::{
+    Conversation, ConversationAction, ConversationType, ConversationUpdate, MediaFile, Message,
+    MessageContent, NewMessage, SeenReceipt, SpanKind, UniformResourceName,
+    MAX_MESSAGE_IMAGE_BYTES,
+};
+use serde_json::json;
+
+#[test]
+fn test_parse_inbox_conversation() {
+    let element = json!({
+        "entityUrn": "urn:li:fs_conversation:2-YWJj==",
+        "participants": [{
+            "com.linkedin.voyager.messaging.MessagingMember": {
+                "miniProfile": {
+                    "entityUrn": "urn:li:fs_miniProfile:ACoAAB",
+                    "firstName": "Jane",
+                    "lastName": "Doe",
+                    "publicIdentifier": "jane-doe"
+                }
+            }
+        }],
+        "unreadCount": 2,
+        "read": false,
+        "lastActivityAt": 1700000000000i64,
+        "notificationStatus": "MUTE",
+        "archived": false,
+        "groupChat": false,
+        "events": [{
+            "subtype": "INMAIL",
+            "eventContent": {
+                "com.linkedin.voyager.messaging.event.MessageEvent": {
+                    "attributedBody": { "text": "Hi there" }
+                }
+            }
+        }]
+    });
+
+    let conversation = Conversation::from_voyager(&element).unwrap();
+
+    assert_eq!(conversation.id, "2-YWJj==");
+    assert_eq!(conversation.conversation_type, ConversationType::InMail);
+    assert_eq!(
+        conversation.participants[0].first_name.as_deref(),
+        Some("Jane")
+    );
+    assert_eq!(
+        conversation.participants[0].urn().unwrap().as_str(),
+        "urn:li:fs_miniProfile:ACoAAB"
+    );
+    assert_eq!(conversation.unread_count, 2);
+    assert!(!conversation.read);
+    assert!(conversation.muted);
+    assert_eq!(conversation.last_message.as_deref(), Some("Hi there"));
+    assert_eq!(
+        conversation.last_activity_at.unwrap().unix_timestamp(),
+        1_700_000_000
+    );
+}
+
+#[test]
+fn test_conversation_without_urn_is_skipped() {
+    assert!(Conversation::from_voyager(&json!({ "unreadCount": 1 })).is_none());
+}
+
+#[test]
+fn test_parse_message_event() {
+    let element = json!({
+        "entityUrn": "urn:li:fs_event:(2-YWJj==,5-ZGVm)",
+        "createdAt": 1700000000000i64,
+        "subtype": "MEMBER_TO_MEMBER",
+        "from": {
+            "com.linkedin.voyager.messaging.MessagingMember": {
+                "miniProfile": { "firstName": "Jane", "lastName": "Doe" }
+            }
+        },
+        "eventContent": {
+            "com.linkedin.voyager.messaging.event.MessageEvent": {
+                "attributedBody": {
+                    "text": "Hi Bob, see the deck",
+                    "attributes": [{
+                        "start": 3,
+                        "length": 3,
+                        "type": {
+                            "com.linkedin.pemberly.text.Entity": {
+                                "urn": "urn:li:fs_miniProfile:ACoAAB"
+                            }
+                        }
+                    }]
+                },
+                "attachments": [{
+                    "name": "deck.pdf",
+                    "mediaType": "application/pdf",
+                    "byteSize": 2048,
+                    "reference": { "string": "https://example.com/deck.pdf" }
+                }]
+            }
+        },
+        "reactionSummaries": [{ "emoji": "👍", "count": 2, "viewerReacted": true }]
+    });
+
+    let message = Message::from_voyager(&element);
+
+    assert_eq!(message.text(), Some("Hi Bob, see the deck"));
+    assert_eq!(message.sender.unwrap().first_name.as_deref(), Some("Jane"));
+    assert_eq!(message.reactions[0].count, 2);
+    let MessageContent::Message {
+        body, attachments, ..
+    } = &message.content
+    else {
+        panic!("expected a message, got {:?}", message.content);
+    };
+    assert!(matches!(
+        body.spans[0].kind,
+        SpanKind::ProfileMention { .. }
+    ));
+    assert_eq!(attachments[0].byte_size, Some(2048));
+}
+
+#[test]
+fn test_parse_participant_change() {
+    let element = json!({
+        "eventContent": {
+            "com.linkedin.voyager.messaging.event.ParticipantChangeEvent": {
+                "addedParticipants": [{
+                    "com.linkedin.voyager.messaging.MessagingMember": {
+                        "miniProfile": { "firstName": "Bob" }
+                    }
+                }]
+            }
+        }
+    });
+
+    let message = Message::from_voyager(&element);
+
+    assert!(message.text().is_none());
+    assert!(matches!(
+        &message.content,
+        MessageContent::ParticipantChange { added, removed } if added.len() == 1 && removed.is_empty()
+    ));
+}
+
+#[test]
+fn test_inbox_filter_matches() {
+    let conversation = Conversation::from_voyager(&json!({
+        "entityUrn": "urn:li:fs_conversation:2-YWJj==",
+        "read": true,
+        "participants": [{
+            "com.linkedin.voyager.messaging.MessagingMember": {
+                "miniProfile": { "entityUrn": "urn:li:fs_miniProfile:ACoAAB" }
+            }
+        }]
+    }))
+    .unwrap();
+
+    let participant = UniformResourceName::parse("urn:li:fsd_profile:ACoAAB").unwrap();
+    let stranger = UniformResourceName::parse("urn:li:fsd_profile:ACoAAC").unwrap();
+
+    assert!(InboxFilter::All.matches(&conversation));
+    assert!(!InboxFilter::Unread.matches(&conversation));
+    assert!(!InboxFilter::InMail.matches(&conversation));
+    assert!(InboxFilter::Participant(participant).matches(&conversation));
+    assert!(!InboxFilter::Participant(stranger).matches(&conversation));
+}
+
+#[test]
+fn test_new_message_builder_validates() {
+    let urn: UniformResourceName = "urn:li:fsd_profile:ACoAAB".parse().unwrap();
+    let message = NewMessage::builder()
+        .text("Hi ")
+        .mention("Bob", &urn)
+        .link("https://example.com".parse().unwrap())
+        .attachment(MediaFile::new("notes.pdf", vec![0; 16]))
+        .build()
+        .unwrap();
+    assert_eq!(message.body.spans.len(), 2);
+    assert!(matches!(
+        &message.body.spans[0].kind,
+        SpanKind::ProfileMention { urn: Some(u), .. } if *u == urn
+    ));
+    assert_eq!(message.body.span_text(&message.body.spans[0]), "Bob");
+
+    assert!(NewMessage::builder().build().is_err());
+    assert!(NewMessage::builder()
+        .image(MediaFile::new("notes.pdf", vec![0; 16]))
+        .build()
+        .is_err());
+    assert!(NewMessage::builder()
+        .image(MediaFile::new(
+            "big.png",
+            vec![0; MAX_MESSAGE_IMAGE_BYTES + 1]
+        ))
+        .build()
+        .is_err());
+}
+
+#[test]
+fn test_parse_seen_receipt() {
+    let receipt = SeenReceipt::from_voyager(&json!({
+        "fromEntity": "urn:li:fs_miniProfile:ACoAAB",
+        "seenReceipt": {
+            "eventUrn": "urn:li:fs_event:(2-YWJj==,5-ZGVm)",
+            "seenAt": 1700000000000i64
+        }
+    }));
+
+    assert_eq!(receipt.reader.unwrap().id, "ACoAAB");
+    assert_eq!(receipt.event_urn.unwrap().id, "(2-YWJj==,5-ZGVm)");
+    assert_eq!(receipt.seen_at.unwrap().unix_timestamp(), 1_700_000_000);
+}
+
+#[test]
+fn test_parse_conversation_update() {
+    let requested = UniformResourceName::parse("urn:li:fs_conversation:2-YWJj==").unwrap();
+
+    let empty = ConversationUpdate::from_voyager(
+        requested.clone(),
+        ConversationAction::Archive,
+        &serde_json::Value::Null,
+    );
+    assert_eq!(empty.conversation_urn, requested);
+    assert!(empty.conversation.is_none());
+
+    let returned = ConversationUpdate::from_voyager(
+        requested.clone(),
+        ConversationAction::Rename("Team".into()),
+        &json!({ "value": {
+            "entityUrn": "urn:li:fs_conversation:2-YWJj==",
+            "name": "Team",
+            "groupChat": true,
+            "archived": true
+        }}),
+    );
+    let conversation = returned.conversation.unwrap();
+    assert_eq!(conversation.name.as_deref(), Some("Team"));
+    assert_eq!(conversation.conversation_type, ConversationType::Group);
+    assert!(conversation.archived);
+    assert_eq!(returned.conversation_urn, requested);
+}
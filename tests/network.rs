@@ -0,0 +1,196 @@
+use linkedin_api::crawler::{CrawlEdge, CrawlGraph, CrawlNode};
+use linkedin_api::types::{
+    Connection, FollowKind, FollowedEntity, NetworkDistance, NetworkInfo, OwnConnection,
+    SharedConnections, UniformResourceName,
+};
+use serde_json::json;
+
+#[test]
+fn test_parse_own_connection() {
+    let element = json!({
+        "createdAt": 1700000000000i64,
+        "connectedMember": "urn:li:fsd_profile:ACoAAB",
+        "connectedMemberResolutionResult": {
+            "firstName": "Jane",
+            "lastName": "Doe",
+            "headline": "Engineer at Example",
+            "publicIdentifier": "jane-doe",
+            "profilePicture": {
+                "displayImageReference": {
+                    "vectorImage": {
+                        "rootUrl": "https://media.licdn.com/dms/image/",
+                        "artifacts": [
+                            { "width": 100, "height": 100, "fileIdentifyingUrlPathSegment": "small" },
+                            { "width": 400, "height": 400, "fileIdentifyingUrlPathSegment": "large" }
+                        ]
+                    }
+                }
+            }
+        }
+    });
+
+    let connection = OwnConnection::from_voyager(&element).unwrap();
+
+    assert_eq!(connection.urn.id, "ACoAAB");
+    assert_eq!(connection.public_id.as_deref(), Some("jane-doe"));
+    assert_eq!(connection.headline.as_deref(), Some("Engineer at Example"));
+    assert_eq!(
+        connection.picture.unwrap().as_str(),
+        "https://media.licdn.com/dms/image/large"
+    );
+    assert_eq!(
+        connection.connected_at.unwrap().unix_timestamp(),
+        1_700_000_000
+    );
+
+    assert!(OwnConnection::from_voyager(&json!({ "createdAt": 1 })).is_none());
+}
+
+fn crawl_node(urn_id: &str, name: Option<&str>, depth: usize) -> CrawlNode {
+    CrawlNode {
+        urn_id: urn_id.to_string(),
+        public_id: None,
+        name: name.map(str::to_string),
+        headline: None,
+        distance: None,
+        depth,
+    }
+}
+
+#[test]
+fn test_crawl_graph_dedupes_by_urn() {
+    let mut graph = CrawlGraph::default();
+    graph.add_node(crawl_node("a", None, 2));
+    graph.add_node(crawl_node("a", Some("Jane Doe"), 1));
+    graph.add_node(crawl_node("b", None, 1));
+    graph.add_edge("a", "b");
+    graph.add_edge("b", "a");
+    graph.add_edge("a", "a");
+
+    assert_eq!(graph.nodes.len(), 2);
+    assert_eq!(graph.nodes["a"].depth, 1);
+    assert_eq!(graph.nodes["a"].name.as_deref(), Some("Jane Doe"));
+    assert_eq!(
+        graph.edges.iter().collect::<Vec<_>>(),
+        vec![&CrawlEdge::new("b", "a")]
+    );
+}
+
+#[test]
+fn test_parse_followed_entities() {
+    let company = FollowedEntity::from_voyager(&json!({
+        "entity": {
+            "com.linkedin.voyager.identity.shared.MiniCompany": {
+                "entityUrn": "urn:li:fs_miniCompany:1441",
+                "name": "Example"
+            }
+        },
+        "followingInfo": { "followerCount": 1200, "following": true }
+    }))
+    .unwrap();
+    assert_eq!(company.kind, FollowKind::Company);
+    assert_eq!(company.urn.id, "1441");
+    assert_eq!(company.name.as_deref(), Some("Example"));
+    assert_eq!(company.followers_count, Some(1200));
+
+    let member = FollowedEntity::from_voyager(&json!({
+        "entity": {
+            "com.linkedin.voyager.identity.shared.MiniProfile": {
+                "entityUrn": "urn:li:fs_miniProfile:ACoAAB",
+                "firstName": "Jane",
+                "lastName": "Doe"
+            }
+        }
+    }))
+    .unwrap();
+    assert_eq!(member.kind, FollowKind::Member);
+    assert_eq!(member.name.as_deref(), Some("Jane Doe"));
+    assert_eq!(member.followers_count, None);
+
+    let hashtag = FollowedEntity::from_voyager(&json!({
+        "entity": { "entityUrn": "urn:li:hashtag:rust", "name": "#rust" }
+    }))
+    .unwrap();
+    assert_eq!(hashtag.kind, FollowKind::Hashtag);
+    assert_eq!(hashtag.name.as_deref(), Some("rust"));
+
+    // Not a followable entity
+    assert!(FollowedEntity::from_voyager(&json!({
+        "entity": { "entityUrn": "urn:li:fs_job:1" }
+    }))
+    .is_none());
+}
+
+#[test]
+fn test_follow_kind_urn() {
+    let urn = FollowKind::School.urn("12345");
+    assert_eq!(urn.to_string(), "urn:li:fsd_school:12345");
+    assert_eq!(FollowKind::from_urn(&urn), Some(FollowKind::School));
+    assert_eq!(
+        FollowKind::from_urn(&UniformResourceName::parse("urn:li:fs_job:1").unwrap()),
+        None
+    );
+}
+
+#[test]
+fn test_parse_network_info() {
+    let info = NetworkInfo::from_voyager(&json!({
+        "data": {
+            "entityUrn": "urn:li:fs_profileNetworkInfo:ACoAAB",
+            "followersCount": 812,
+            "connectionsCount": 500,
+            "distance": { "value": "DISTANCE_2" },
+            "following": false,
+            "followable": true
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(info.entity_urn.unwrap().id, "ACoAAB");
+    assert_eq!(info.followers_count, 812);
+    assert_eq!(info.connections_count, Some(500));
+    assert_eq!(info.distance, Some(NetworkDistance::Second));
+    assert_eq!(info.following, Some(false));
+    assert_eq!(info.followable, Some(true));
+
+    // Missing fields stay unknown rather than reading as zero or false
+    let info = NetworkInfo::from_voyager(&json!({ "followersCount": 3 })).unwrap();
+    assert_eq!(info.connections_count, None);
+    assert_eq!(info.following, None);
+    assert_eq!(info.followable, None);
+
+    // A payload without counts is an error upstream, not zero followers
+    assert!(NetworkInfo::from_voyager(&json!({ "status": 403 })).is_none());
+}
+
+#[test]
+fn test_parse_shared_connections() {
+    let listed = vec![Connection {
+        urn_id: "ACoAAB".into(),
+        public_id: "jane-doe".into(),
+        distance: "DISTANCE_1".into(),
+        name: Some("Jane Doe".into()),
+        headline: None,
+    }];
+    let highlights = json!({ "elements": [
+        { "connectionSummary": {} },
+        { "sharedConnectionsInfo": { "numberOfSharedConnections": 0 } }
+    ]});
+
+    // The reported total is kept even when it disagrees with the listed connections
+    let shared = SharedConnections::from_highlights(&highlights, listed.clone());
+    assert_eq!(shared.count, Some(0));
+    assert_eq!(shared.connections.len(), 1);
+
+    let legacy = json!({ "elements": [
+        { "sharedConnections": { "numberOfSharedConnections": 57 } }
+    ]});
+    assert_eq!(
+        SharedConnections::from_highlights(&legacy, vec![]).count,
+        Some(57)
+    );
+    assert_eq!(
+        SharedConnections::from_highlights(&json!({ "elements": [] }), listed).count,
+        None
+    );
+}
@@ -0,0 +1,76 @@
+use linkedin_api::client::is_linkedin_host;
+use linkedin_api::types::{MediaFile, NewPost, SpanKind, UniformResourceName, MAX_POST_LENGTH};
+use url::Url;
+
+#[test]
+fn test_builder_tracks_spans() {
+    let urn = UniformResourceName::parse("urn:li:fsd_profile:ACoAAB").unwrap();
+    let post = NewPost::builder()
+        .text("Great talk by ")
+        .mention("Jane Doe", &urn)
+        .text("! ")
+        .hashtag("rust")
+        .build()
+        .unwrap();
+
+    assert_eq!(post.commentary.text, "Great talk by Jane Doe! #rust");
+    assert_eq!(
+        post.commentary.span_text(&post.commentary.spans[0]),
+        "Jane Doe"
+    );
+    assert_eq!(
+        post.commentary.span_text(&post.commentary.spans[1]),
+        "#rust"
+    );
+    assert!(matches!(
+        &post.commentary.spans[1].kind,
+        SpanKind::Hashtag { tag } if tag == "rust"
+    ));
+}
+
+#[test]
+fn test_spans_count_utf16_units() {
+    let urn = UniformResourceName::parse("urn:li:fsd_profile:ACoAAB").unwrap();
+    let post = NewPost::builder()
+        .text("🦀 Talk by ")
+        .mention("Zoë", &urn)
+        .text(" ")
+        .hashtag("rust")
+        .build()
+        .unwrap();
+
+    // The crab is two UTF-16 units
+    let mention = &post.commentary.spans[0];
+    assert_eq!((mention.start, mention.length), (11, 3));
+    assert_eq!(post.commentary.span_text(mention), "Zoë");
+    assert_eq!(post.commentary.spans[1].start, 15);
+    assert_eq!(
+        post.commentary.span_text(&post.commentary.spans[1]),
+        "#rust"
+    );
+}
+
+#[test]
+fn test_upload_credentials_stay_on_linkedin() {
+    let host = |url: &str| is_linkedin_host(&Url::parse(url).unwrap());
+
+    assert!(host("https://www.linkedin.com/dms-uploads/abc"));
+    assert!(host("https://linkedin.com/upload"));
+    assert!(!host("https://evillinkedin.com/upload"));
+    assert!(!host("https://linkedin.com.example.org/upload"));
+    assert!(!host("https://media.licdn.example/upload"));
+}
+
+#[test]
+fn test_builder_validation() {
+    assert!(NewPost::builder().build().is_err());
+    assert!(NewPost::builder()
+        .text(&"a".repeat(MAX_POST_LENGTH + 1))
+        .build()
+        .is_err());
+    assert!(NewPost::builder()
+        .link(Url::parse("https://example.com").unwrap())
+        .image(MediaFile::new("a.png", vec![0]), None)
+        .build()
+        .is_err());
+}
@@ -0,0 +1,206 @@
+use std::time::Duration;
+
+use linkedin_api::realtime::{ActivityTracker, EventSource, RealtimeEvent, SseEvent};
+use linkedin_api::types::Conversation;
+use linkedin_api::LinkedinError;
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+
+/// Serve one canned SSE response per connection, reporting each request head.
+async fn serve(
+    responses: Vec<(&'static str, &'static str)>,
+) -> (String, mpsc::UnboundedReceiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/realtime/connect", listener.local_addr().unwrap());
+    let (requests, received) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        for (status, body) in responses {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut head = vec![];
+            let mut byte = [0u8; 1];
+            while !head.ends_with(b"\r\n\r\n") {
+                socket.read_exact(&mut byte).await.unwrap();
+                head.push(byte[0]);
+            }
+            requests
+                .send(String::from_utf8_lossy(&head).to_lowercase())
+                .unwrap();
+
+            let response = format!(
+                "HTTP/1.1 {status}\r\ncontent-type: text/event-stream\r\nconnection: close\r\n\r\n{body}"
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            socket.shutdown().await.unwrap();
+        }
+    });
+
+    (url, received)
+}
+
+#[tokio::test]
+async fn test_event_source_reconnects_from_last_event_id() {
+    let (url, mut requests) = serve(vec![
+        ("200 OK", ": heartbeat\n\nid: 1\ndata: first\n\n"),
+        (
+            "200 OK",
+            "id: 2\nevent: message\ndata: second\ndata: line\n\n",
+        ),
+    ])
+    .await;
+
+    let mut source = EventSource::new(reqwest::Client::new(), url.parse().unwrap())
+        .max_retries(1)
+        .backoff(Duration::from_millis(10), Duration::from_millis(10));
+
+    let first = source.next().await.unwrap();
+    assert_eq!(first.data, "first");
+
+    let second = source.next().await.unwrap();
+    assert_eq!(second.event.as_deref(), Some("message"));
+    assert_eq!(second.data, "second\nline");
+
+    assert!(requests
+        .recv()
+        .await
+        .unwrap()
+        .contains("accept: text/event-stream"));
+    assert!(requests.recv().await.unwrap().contains("last-event-id: 1"));
+
+    // The stand-in has stopped accepting, so retries run out
+    assert!(source.next().await.is_err());
+}
+
+#[tokio::test]
+async fn test_event_source_reports_http_status() {
+    let (url, _requests) = serve(vec![
+        ("429 Too Many Requests", ""),
+        ("429 Too Many Requests", ""),
+    ])
+    .await;
+    let mut source = EventSource::new(reqwest::Client::new(), url.parse().unwrap())
+        .max_retries(1)
+        .backoff(Duration::from_millis(10), Duration::from_millis(10));
+    assert!(matches!(source.next().await, Err(LinkedinError::RateLimit)));
+
+    // Unauthorized isn't retried
+    let (url, _requests) = serve(vec![("401 Unauthorized", "")]).await;
+    let mut source = EventSource::new(reqwest::Client::new(), url.parse().unwrap())
+        .max_retries(3)
+        .backoff(Duration::from_millis(10), Duration::from_millis(10));
+    assert!(matches!(
+        source.next().await,
+        Err(LinkedinError::Unauthorized(_))
+    ));
+}
+
+fn conversation(id: &str, last_activity_at: i64) -> Conversation {
+    Conversation::from_voyager(&json!({
+        "entityUrn": format!("urn:li:fs_conversation:{id}"),
+        "lastActivityAt": last_activity_at
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_fallback_poll_skips_streamed_activity() {
+    let (url, _requests) = serve(vec![(
+        "200 OK",
+        concat!(
+            "data: {\"com.linkedin.realtimefrontend.DecoratedEvent\": {",
+            "\"topic\": \"urn:li-realtime:messagesTopic:urn:li-realtime:myself\",",
+            "\"payload\": {\"event\": {\"entityUrn\": \"urn:li:fs_event:(2-AAA,5-ZGVm)\",",
+            "\"createdAt\": 1700000002000}}}}\n\n",
+            "data: {\"com.linkedin.realtimefrontend.DecoratedEvent\": {",
+            "\"topic\": \"urn:li-realtime:conversationsTopic:urn:li-realtime:myself\",",
+            "\"payload\": {\"conversation\": {\"entityUrn\": \"urn:li:fs_conversation:2-BBB\",",
+            "\"lastActivityAt\": 1700000003000}}}}\n\n",
+        ),
+    )])
+    .await;
+    let mut source = EventSource::new(reqwest::Client::new(), url.parse().unwrap())
+        .max_retries(0)
+        .backoff(Duration::from_millis(10), Duration::from_millis(10));
+
+    // A baseline from an earlier polling period
+    let mut activity = ActivityTracker::default();
+    let baseline = vec![
+        conversation("2-AAA", 1700000000000),
+        conversation("2-BBB", 1700000000000),
+        conversation("2-CCC", 1700000000000),
+    ];
+    assert!(activity.changes(baseline).is_empty());
+
+    for _ in 0..2 {
+        let sse = source.next().await.unwrap();
+        activity.record(&RealtimeEvent::from_sse(&sse.data).unwrap());
+    }
+    // The stream ends and can't be reconnected, so the subscription would poll
+    assert!(source.next().await.is_err());
+
+    let changed = activity.changes(vec![
+        conversation("2-AAA", 1700000002000),
+        conversation("2-BBB", 1700000003000),
+        conversation("2-CCC", 1700000004000),
+    ]);
+    let ids: Vec<_> = changed
+        .iter()
+        .map(|event| match event {
+            RealtimeEvent::ConversationUpdate(conversation) => conversation.id.as_str(),
+            other => panic!("expected a conversation update, got {other:?}"),
+        })
+        .collect();
+    assert_eq!(ids, ["2-CCC"]);
+}
+
+#[test]
+fn test_parse_sse_block() {
+    assert!(SseEvent::parse(": keep-alive").is_none());
+
+    let event = SseEvent::parse("event: update\ndata:{\"a\":1}\nid: 7").unwrap();
+    assert_eq!(event.event.as_deref(), Some("update"));
+    assert_eq!(event.data, "{\"a\":1}");
+    assert_eq!(event.id.as_deref(), Some("7"));
+}
+
+#[test]
+fn test_parse_realtime_events() {
+    let typing = r#"{"com.linkedin.realtimefrontend.DecoratedEvent": {
+        "topic": "urn:li-realtime:typingIndicatorsTopic:urn:li-realtime:myself",
+        "payload": {
+            "conversation": "urn:li:fs_conversation:2-YWJj==",
+            "fromEntity": "urn:li:fs_miniProfile:ACoAAB"
+        }
+    }}"#;
+    let Some(RealtimeEvent::Typing {
+        conversation_urn,
+        from,
+    }) = RealtimeEvent::from_sse(typing)
+    else {
+        panic!("expected a typing indicator");
+    };
+    assert_eq!(conversation_urn.unwrap().id, "2-YWJj==");
+    assert_eq!(from.unwrap().id, "ACoAAB");
+
+    let message = r#"{"com.linkedin.realtimefrontend.DecoratedEvent": {
+        "topic": "urn:li-realtime:messagesTopic:urn:li-realtime:myself",
+        "payload": { "event": {
+            "entityUrn": "urn:li:fs_event:(2-YWJj==,5-ZGVm)",
+            "createdAt": 1700000000000,
+            "eventContent": {
+                "com.linkedin.voyager.messaging.event.MessageEvent": {
+                    "attributedBody": { "text": "Hello" }
+                }
+            }
+        }}
+    }}"#;
+    let Some(RealtimeEvent::NewMessage(message)) = RealtimeEvent::from_sse(message) else {
+        panic!("expected a new message");
+    };
+    assert_eq!(message.text(), Some("Hello"));
+
+    let heartbeat = r#"{"com.linkedin.realtimefrontend.Heartbeat": {}}"#;
+    assert!(RealtimeEvent::from_sse(heartbeat).is_none());
+}